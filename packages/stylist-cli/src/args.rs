@@ -0,0 +1,153 @@
+use std::path::PathBuf;
+
+use crate::error::{Error, Result};
+
+pub const USAGE: &str = "\
+Usage: stylist <COMMAND> [OPTIONS] [FILE]...
+
+Commands:
+    check [--validate]    Parses stylesheets and reports errors with their locations
+                          With --validate, unknown properties are reported as well
+    fmt [--check]         Formats stylesheets in place, or reports unformatted stylesheets with --check
+                          Stylesheets with comments are left unchanged, as formatting removes them
+    compile [--class NAME]
+                          Prints the CSS scoped to the class name, or global CSS without --class
+    help                  Prints this message
+
+Stylesheets are read from stdin if no file or `-` is passed.";
+
+/// Where a stylesheet is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    pub fn name(&self) -> String {
+        match self {
+            Self::Stdin => "<stdin>".to_string(),
+            Self::File(ref m) => m.display().to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Check {
+        validate: bool,
+        inputs: Vec<Input>,
+    },
+    Fmt {
+        check: bool,
+        inputs: Vec<Input>,
+    },
+    Compile {
+        class_name: Option<String>,
+        inputs: Vec<Input>,
+    },
+    Help,
+}
+
+impl Command {
+    /// Parses the command from the arguments, excluding the name of the binary.
+    pub fn parse<I>(args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        let command = match args.next() {
+            Some(m) => m,
+            None => return Ok(Self::Help),
+        };
+
+        let mut check = false;
+        let mut validate = false;
+        let mut class_name = None;
+        let mut inputs = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match (command.as_str(), arg.as_str()) {
+                ("check", "--validate") => validate = true,
+                ("fmt", "--check") => check = true,
+                ("compile", "--class") => {
+                    let name = args
+                        .next()
+                        .ok_or_else(|| Error::Usage("--class requires a class name".to_string()))?;
+                    class_name = Some(name);
+                }
+                (_, "-") => inputs.push(Input::Stdin),
+                (_, m) if m.starts_with("--") => {
+                    return Err(Error::Usage(format!(
+                        "unknown option `{}` for `{}`",
+                        m, command
+                    )))
+                }
+                (_, m) => inputs.push(Input::File(m.into())),
+            }
+        }
+
+        if inputs.is_empty() {
+            inputs.push(Input::Stdin);
+        }
+
+        match command.as_str() {
+            "check" => Ok(Self::Check { validate, inputs }),
+            "fmt" => Ok(Self::Fmt { check, inputs }),
+            "compile" => Ok(Self::Compile { class_name, inputs }),
+            "help" | "--help" | "-h" => Ok(Self::Help),
+            m => Err(Error::Usage(format!("unknown command `{}`", m))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command> {
+        Command::parse(args.iter().map(|m| m.to_string()))
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            parse(&["check", "a.css", "-"]).unwrap(),
+            Command::Check {
+                validate: false,
+                inputs: vec![Input::File("a.css".into()), Input::Stdin],
+            }
+        );
+        assert_eq!(
+            parse(&["check", "--validate", "a.css"]).unwrap(),
+            Command::Check {
+                validate: true,
+                inputs: vec![Input::File("a.css".into())],
+            }
+        );
+        assert_eq!(
+            parse(&["fmt", "--check"]).unwrap(),
+            Command::Fmt {
+                check: true,
+                inputs: vec![Input::Stdin],
+            }
+        );
+        assert_eq!(
+            parse(&["compile", "--class", "foo", "a.css"]).unwrap(),
+            Command::Compile {
+                class_name: Some("foo".to_string()),
+                inputs: vec![Input::File("a.css".into())],
+            }
+        );
+        assert_eq!(parse(&[]).unwrap(), Command::Help);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&["lint"]).is_err());
+        assert!(parse(&["check", "--class", "foo"]).is_err());
+        assert!(parse(&["compile", "--class"]).is_err());
+        assert!(parse(&["fmt", "--validate"]).is_err());
+    }
+}
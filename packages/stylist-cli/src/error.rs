@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The arguments are invalid.
+    #[error("{}", .0)]
+    Usage(String),
+
+    /// Failed to read or write a stylesheet.
+    #[error("{}: {}", .name, .source)]
+    Io {
+        name: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The stylesheet failed to parse or validate.
+    #[error("{}: {}", .name, .source)]
+    Css {
+        name: String,
+        #[source]
+        source: stylist_core::Error,
+    },
+
+    /// The stylesheet failed to parse or validate at a known location.
+    #[error("{}:{}:{}: {}", .name, .line, .column, .source)]
+    CssAt {
+        name: String,
+        line: usize,
+        column: usize,
+        #[source]
+        source: stylist_core::Error,
+    },
+
+    /// The stylesheet is not formatted, raised by `fmt --check`.
+    #[error("{}: stylesheet is not formatted", .name)]
+    Unformatted { name: String },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,275 @@
+//! A command line tool to check, format and compile stylesheets with the same parser as
+//! Stylist uses at runtime.
+
+#![deny(clippy::all)]
+#![deny(missing_debug_implementations)]
+#![deny(unsafe_code)]
+#![deny(non_snake_case)]
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+
+use stylist_core::ast::{Sheet, ToStyleStr};
+use stylist_core::line_column;
+use stylist_core::validation::find_declaration;
+
+mod args;
+mod error;
+
+use args::{Command, Input, USAGE};
+use error::{Error, Result};
+
+/// An error of a stylesheet and the byte offset in the stylesheet where it occurred, if known.
+type CssResult<T> = std::result::Result<T, (stylist_core::Error, Option<usize>)>;
+
+fn parse_source(source: &str) -> CssResult<Sheet> {
+    source.parse().map_err(|e: stylist_core::Error| {
+        let offset = e.parse_offset(source);
+        (e, offset)
+    })
+}
+
+/// Returns whether `source` contains a comment outside of strings.
+fn has_comments(source: &str) -> bool {
+    let mut quote = None;
+    let mut escaped = false;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(_), _) if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(m), _) if m == c => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '/') if chars.peek() == Some(&'*') => return true,
+            (None, _) => {}
+        }
+    }
+
+    false
+}
+
+/// Parses a stylesheet and, if `validate` is set, checks its style attributes against the
+/// bundled CSS properties.
+fn check_source(source: &str, validate: bool) -> CssResult<Sheet> {
+    let sheet = parse_source(source)?;
+
+    if !validate {
+        return Ok(sheet);
+    }
+
+    if let Some((attr, e)) = sheet.first_invalid_attribute() {
+        return Err((e, find_declaration(source, &attr.key)));
+    }
+
+    Ok(sheet)
+}
+
+/// Formats a stylesheet in the canonical format, which is its authored CSS printed by
+/// [`Display`](std::fmt::Display).
+///
+/// The parser does not keep comments, so stylesheets with comments are only parsed and returned
+/// unchanged.
+fn format_source(source: &str) -> CssResult<String> {
+    let sheet = parse_source(source)?;
+
+    if has_comments(source) {
+        return Ok(source.to_string());
+    }
+
+    Ok(sheet.to_string())
+}
+
+/// Compiles a stylesheet into the CSS that is mounted for `class_name`, or global CSS if it is
+/// `None`.
+fn compile_source(source: &str, class_name: Option<&str>) -> CssResult<String> {
+    Ok(parse_source(source)?.to_style_str(class_name))
+}
+
+fn read_input(input: &Input) -> Result<String> {
+    let result = match input {
+        Input::Stdin => {
+            let mut s = String::new();
+            io::stdin().read_to_string(&mut s).map(|_| s)
+        }
+        Input::File(ref m) => fs::read_to_string(m),
+    };
+
+    result.map_err(|source| Error::Io {
+        name: input.name(),
+        source,
+    })
+}
+
+fn write_output(input: &Input, content: &str) -> Result<()> {
+    let result = match input {
+        Input::Stdin => io::stdout().write_all(content.as_bytes()),
+        Input::File(ref m) => fs::write(m, content),
+    };
+
+    result.map_err(|source| Error::Io {
+        name: input.name(),
+        source,
+    })
+}
+
+fn run_input(command: &Command, input: &Input) -> Result<()> {
+    let source = read_input(input)?;
+    let css_err = |(e, offset): (stylist_core::Error, Option<usize>)| match offset {
+        Some(m) => {
+            let (line, column) = line_column(&source, m);
+            Error::CssAt {
+                name: input.name(),
+                line,
+                column,
+                source: e,
+            }
+        }
+        None => Error::Css {
+            name: input.name(),
+            source: e,
+        },
+    };
+
+    match command {
+        Command::Help => Ok(()),
+
+        Command::Check { validate, .. } => check_source(&source, *validate)
+            .map(|_| ())
+            .map_err(css_err),
+
+        Command::Fmt { check, .. } => {
+            let formatted = format_source(&source).map_err(css_err)?;
+
+            if *check && formatted != source {
+                return Err(Error::Unformatted { name: input.name() });
+            } else if *check {
+                return Ok(());
+            }
+
+            // Files are only written if they change.
+            match input {
+                Input::File(_) if formatted == source => Ok(()),
+                _ => write_output(input, &formatted),
+            }
+        }
+
+        Command::Compile { class_name, .. } => {
+            let compiled = compile_source(&source, class_name.as_deref()).map_err(css_err)?;
+            write_output(&Input::Stdin, &compiled)
+        }
+    }
+}
+
+/// Runs the command for each input and returns the errors of all inputs.
+fn run(command: &Command) -> Vec<Error> {
+    let inputs = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            return Vec::new();
+        }
+        Command::Check { inputs, .. }
+        | Command::Fmt { inputs, .. }
+        | Command::Compile { inputs, .. } => inputs,
+    };
+
+    inputs
+        .iter()
+        .filter_map(|m| run_input(command, m).err())
+        .collect()
+}
+
+fn main() {
+    let command = match Command::parse(std::env::args().skip(1)) {
+        Ok(m) => m,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let errors = run(&command);
+
+    for e in errors.iter() {
+        eprintln!("error: {}", e);
+    }
+
+    if !errors.is_empty() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check_source("color: red; .a { width: 1px; }", true).is_ok());
+        assert!(check_source("color: red; .a { width: 1px;", true).is_err());
+        assert!(check_source("color: red; .a { width: 1px;", false).is_err());
+        assert!(check_source("colour: red;", true).is_err());
+        assert!(check_source("colour: red;", false).is_ok());
+    }
+
+    #[test]
+    fn test_check_location() {
+        let source = "color: red;\n.a {\n    colour: red;\n}\n";
+        let (_, offset) = check_source(source, true).expect_err("Failed to find invalid property.");
+
+        assert_eq!(offset.map(|m| line_column(source, m)), Some((3, 5)));
+
+        let source = "color: red;\n.a {\n    color: blue;\n";
+        let (_, offset) = check_source(source, false).expect_err("Failed to find parse error.");
+
+        assert_eq!(offset.map(|m| line_column(source, m)), Some((2, 1)));
+    }
+
+    #[test]
+    fn test_has_comments() {
+        assert!(has_comments("/* header */ color: red;"));
+        assert!(has_comments(".a { color: red; /* primary */ }"));
+        assert!(!has_comments("content: \"/* not a comment */\";"));
+        assert!(!has_comments("content: '\\'/*';"));
+        assert!(!has_comments("background: url(a/b.png);"));
+    }
+
+    #[test]
+    fn test_format() {
+        let formatted = format_source("color:red;&:hover{color:blue;}").expect("Failed to format.");
+
+        assert_eq!(
+            formatted,
+            r#"color: red;
+&:hover {
+    color: blue;
+}
+"#
+        );
+        assert_eq!(
+            format_source(&formatted).expect("Failed to format."),
+            formatted
+        );
+    }
+
+    #[test]
+    fn test_format_comments() {
+        let source = "/* primary */\ncolor:red;";
+        assert_eq!(format_source(source).expect("Failed to format."), source);
+
+        assert!(format_source("/* primary */\ncolor:red;&:hover{").is_err());
+    }
+
+    #[test]
+    fn test_compile() {
+        assert_eq!(
+            compile_source("color: red;", Some("foo")).expect("Failed to compile."),
+            r#".foo {
+    color: red;
+}
+"#
+        );
+    }
+}
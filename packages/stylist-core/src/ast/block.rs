@@ -12,6 +12,7 @@ use super::{RuleBlockContent, Selector, StyleContext, ToStyleStr};
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Block {
     /// Selector(s) for Current Block
     ///
@@ -0,0 +1,216 @@
+use std::borrow::Cow;
+
+use super::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+};
+use crate::bow::Bow;
+use crate::{Error, Result};
+
+/// The argument lookup used to fill placeholders.
+pub(crate) type Args<'r, 'a> = &'r dyn Fn(&str) -> Option<Cow<'a, str>>;
+
+/// Structs implementing this trait can substitute `${name}` placeholders with values.
+///
+/// This mirrors what the literal macro does at compile time, but happens at runtime.
+pub(crate) trait Interpolate: Sized {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self>;
+}
+
+fn interpolate_list<T: Interpolate + Clone>(
+    items: &[T],
+    args: Args<'_, '_>,
+) -> Result<Cow<'static, [T]>> {
+    items
+        .iter()
+        .map(|m| m.interpolate(args))
+        .collect::<Result<Vec<T>>>()
+        .map(Cow::Owned)
+}
+
+impl Interpolate for StringFragment {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        if !self.inner.contains("${") {
+            return Ok(self.clone());
+        }
+
+        let mut s = String::with_capacity(self.inner.len());
+        let mut rest: &str = &self.inner;
+
+        while let Some(pos) = rest.find("${") {
+            // `$${` is an escaped `${`.
+            if rest[..pos].ends_with('$') {
+                s.push_str(&rest[..pos - 1]);
+                s.push_str("${");
+                rest = &rest[pos + 2..];
+                continue;
+            }
+
+            s.push_str(&rest[..pos]);
+            let remaining = &rest[pos + 2..];
+
+            let end = match remaining.find('}') {
+                Some(m) => m,
+                // Not a placeholder, keep it as is.
+                None => {
+                    s.push_str(&rest[pos..]);
+                    rest = "";
+                    break;
+                }
+            };
+
+            let name = remaining[..end].trim();
+            let value = args(name).ok_or_else(|| Error::Interpolation {
+                name: name.to_string(),
+            })?;
+            s.push_str(&value);
+
+            rest = &remaining[end + 1..];
+        }
+
+        s.push_str(rest);
+
+        Ok(s.into())
+    }
+}
+
+impl Interpolate for Selector {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        Ok(Self {
+            fragments: interpolate_list(&self.fragments, args)?,
+        })
+    }
+}
+
+impl Interpolate for StyleAttribute {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        Ok(Self {
+            key: self.key.clone(),
+            value: interpolate_list(&self.value, args)?,
+        })
+    }
+}
+
+impl Interpolate for Block {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        Ok(Self {
+            condition: interpolate_list(&self.condition, args)?,
+            content: interpolate_list(&self.content, args)?,
+        })
+    }
+}
+
+impl Interpolate for Rule {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        Ok(Self {
+            condition: interpolate_list(&self.condition, args)?,
+            content: interpolate_list(&self.content, args)?,
+        })
+    }
+}
+
+impl Interpolate for RuleBlockContent {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        Ok(match self {
+            Self::StyleAttr(ref m) => Self::StyleAttr(m.interpolate(args)?),
+            Self::Rule(ref m) => Self::Rule(Bow::Boxed(Box::new(m.interpolate(args)?))),
+            Self::Block(ref m) => Self::Block(Bow::Boxed(Box::new(m.interpolate(args)?))),
+        })
+    }
+}
+
+impl Interpolate for ScopeContent {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        Ok(match self {
+            Self::Block(ref m) => Self::Block(m.interpolate(args)?),
+            Self::Rule(ref m) => Self::Rule(m.interpolate(args)?),
+        })
+    }
+}
+
+impl Interpolate for Sheet {
+    fn interpolate(&self, args: Args<'_, '_>) -> Result<Self> {
+        self.iter()
+            .map(|m| m.interpolate(args))
+            .collect::<Result<Vec<ScopeContent>>>()
+            .map(Sheet::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[test]
+    fn test_interpolate() {
+        let sheet: Sheet = r#"
+            color: ${color};
+
+            ${sel} {
+                content: "$${}";
+            }
+
+            @media screen and ${breakpoint} {
+                display: flex;
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        let args: HashMap<&str, &str> = [
+            ("color", "red"),
+            ("sel", "div.selected"),
+            ("breakpoint", "(max-width: 500px)"),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+
+        let interpolated = sheet
+            .interpolate(|name| args.get(name).map(|m| Cow::Borrowed(*m)))
+            .expect("Failed to interpolate sheet.");
+
+        assert_eq!(
+            interpolated.to_style_str(Some("test")),
+            r#".test {
+    color: red;
+}
+.test div.selected {
+    content: "${}";
+}
+@media screen and (max-width: 500px) {
+    .test {
+        display: flex;
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_interpolate_missing() {
+        let sheet: Sheet = "color: ${color};".parse().expect("Failed to parse sheet.");
+
+        assert_eq!(
+            sheet.interpolate(|_| None),
+            Err(Error::Interpolation {
+                name: "color".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_interpolate_unterminated() {
+        let frag = StringFragment::from(r#""${""#);
+        let args: Args<'_, '_> = &|_| None;
+
+        assert_eq!(
+            frag.interpolate(args)
+                .expect("Failed to interpolate fragment.")
+                .inner,
+            r#""${""#
+        );
+    }
+}
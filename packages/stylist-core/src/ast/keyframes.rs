@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use super::{Rule, Sheet, StringFragment, StyleAttribute, Visitor, VisitorMut};
+
+const KEYFRAMES_RULES: &[&str] = &["@keyframes", "@-webkit-keyframes"];
+const ANIMATION_PROPERTIES: &[&str] = &[
+    "animation",
+    "animation-name",
+    "-webkit-animation",
+    "-webkit-animation-name",
+];
+
+/// Splits the condition of a keyframes rule into the at-rule and the name of the keyframes.
+fn keyframes_name(rule: &Rule) -> Option<(&'static str, String)> {
+    let condition = rule
+        .condition
+        .iter()
+        .map(|m| m.inner.as_ref())
+        .collect::<String>();
+
+    let (at_rule, name) = KEYFRAMES_RULES.iter().find_map(|m| {
+        condition
+            .strip_prefix(m)
+            .filter(|name| name.starts_with(char::is_whitespace))
+            .map(|name| (*m, name.trim()))
+    })?;
+
+    // Quoted names and placeholders are left untouched.
+    let is_ident = !name.is_empty()
+        && name
+            .chars()
+            .all(|m| m.is_alphanumeric() || m == '-' || m == '_');
+
+    is_ident.then(|| (at_rule, name.to_string()))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Replaces identifiers in `value` that are in `names` with their scoped version.
+fn scope_names(value: &str, names: &HashSet<String>, prefix: &str) -> Option<String> {
+    let mut scoped = String::with_capacity(value.len());
+    let mut changed = false;
+    let mut rest = value;
+
+    while let Some(start) = rest.find(is_ident_char) {
+        let (before, ident) = rest.split_at(start);
+        let end = ident.find(|m| !is_ident_char(m)).unwrap_or(ident.len());
+        let (ident, after) = ident.split_at(end);
+
+        scoped.push_str(before);
+        // Identifiers in strings and functions, such as `var(--fade)`, are never names.
+        if names.contains(ident) && !before.ends_with(['"', '\'', '(']) {
+            scoped.push_str(prefix);
+            scoped.push('-');
+            changed = true;
+        }
+        scoped.push_str(ident);
+
+        rest = after;
+    }
+    scoped.push_str(rest);
+
+    changed.then_some(scoped)
+}
+
+impl Sheet {
+    /// Creates a new stylesheet where the names of keyframes defined in this stylesheet are
+    /// prefixed with `prefix`.
+    ///
+    /// References to these keyframes in `animation` and `animation-name` are updated as well, so
+    /// two stylesheets that both define `@keyframes fade` no longer collide.
+    pub fn scope_keyframes(&self, prefix: &str) -> Self {
+        #[derive(Default)]
+        struct KeyframesNames(HashSet<String>);
+
+        impl Visitor for KeyframesNames {
+            fn visit_rule(&mut self, rule: &Rule) {
+                if let Some((_, name)) = keyframes_name(rule) {
+                    self.0.insert(name);
+                }
+                rule.visit_children(self);
+            }
+        }
+
+        struct ScopeKeyframes<'a> {
+            names: HashSet<String>,
+            prefix: &'a str,
+        }
+
+        impl VisitorMut for ScopeKeyframes<'_> {
+            fn visit_rule_mut(&mut self, rule: &mut Rule) {
+                if let Some((at_rule, name)) = keyframes_name(rule) {
+                    let condition: StringFragment =
+                        format!("{} {}-{}", at_rule, self.prefix, name).into();
+                    rule.condition = vec![condition].into();
+                }
+                rule.visit_children_mut(self);
+            }
+
+            fn visit_style_attr_mut(&mut self, attr: &mut StyleAttribute) {
+                if !ANIMATION_PROPERTIES.contains(&attr.key.to_ascii_lowercase().as_str()) {
+                    return;
+                }
+
+                for fragment in attr.value.to_mut().iter_mut() {
+                    if let Some(m) = scope_names(&fragment.inner, &self.names, self.prefix) {
+                        *fragment = m.into();
+                    }
+                }
+            }
+        }
+
+        let mut names = KeyframesNames::default();
+        names.visit_sheet(self);
+
+        if names.0.is_empty() {
+            return self.clone();
+        }
+
+        let mut sheet = self.clone();
+        ScopeKeyframes {
+            names: names.0,
+            prefix,
+        }
+        .visit_sheet_mut(&mut sheet);
+
+        sheet
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[test]
+    fn test_scope_keyframes() {
+        let sheet: Sheet = r#"
+            animation: fade 1s ease-in, spin 2s;
+
+            .inner {
+                animation-name: fade;
+                transition: fade 1s;
+            }
+
+            @keyframes fade {
+                from {
+                    opacity: 0;
+                }
+                to {
+                    opacity: 1;
+                }
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        assert_eq!(
+            sheet.scope_keyframes("test").to_style_str(Some("test")),
+            r#".test {
+    animation: test-fade 1s ease-in, spin 2s;
+}
+.test .inner {
+    animation-name: test-fade;
+    transition: fade 1s;
+}
+@keyframes test-fade {
+    from {
+        opacity: 0;
+    }
+    to {
+        opacity: 1;
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_scope_names() {
+        let names: HashSet<String> = vec!["fade".to_string()].into_iter().collect();
+
+        assert_eq!(
+            scope_names("fade 1s, var(--fade), fade-out", &names, "a"),
+            Some("a-fade 1s, var(--fade), fade-out".to_string())
+        );
+        assert_eq!(scope_names("\"fade\"", &names, "a"), None);
+    }
+}
@@ -2,6 +2,10 @@
 
 mod block;
 mod context;
+mod interpolate;
+mod keyframes;
+mod module;
+mod print;
 mod rule;
 mod rule_block_content;
 mod scope_content;
@@ -10,6 +14,8 @@ mod sheet;
 mod str_frag;
 mod style_attr;
 mod to_style_str;
+mod value;
+mod visit;
 
 pub use context::StyleContext;
 
@@ -21,6 +27,8 @@ pub use selector::Selector;
 pub use sheet::Sheet;
 pub use style_attr::StyleAttribute;
 pub use to_style_str::ToStyleStr;
+pub use value::{Color, Function, Length, LengthUnit, List, Separator, Value};
+pub use visit::{Visitor, VisitorMut};
 
 pub use str_frag::StringFragment;
 
@@ -192,4 +200,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_extend() {
+        let attr = |key: &'static str, value: &'static str| {
+            ScopeContent::Block(Block {
+                condition: Cow::Borrowed(&[]),
+                content: vec![StyleAttribute {
+                    key: key.into(),
+                    value: vec![value.into()].into(),
+                }
+                .into()]
+                .into(),
+            })
+        };
+
+        let mut sheet = Sheet::new();
+        sheet.extend(&Sheet::from(vec![attr("color", "red")]));
+        sheet.extend(&Sheet::from(vec![attr("color", "blue")]));
+
+        assert_eq!(
+            sheet.to_style_str(Some("test")),
+            r#".test {
+    color: red;
+}
+.test {
+    color: blue;
+}
+"#
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() -> Result<()> {
+        let sheet = Sheet::from(vec![
+            ScopeContent::Block(Block {
+                condition: vec![vec![".inner:hover".into()].into()].into(),
+                content: vec![StyleAttribute {
+                    key: "color".into(),
+                    value: vec!["red".into()].into(),
+                }
+                .into()]
+                .into(),
+            }),
+            ScopeContent::Rule(Rule {
+                condition: vec!["@media screen".into()].into(),
+                content: vec![RuleBlockContent::Block(
+                    Block {
+                        condition: Cow::Borrowed(&[]),
+                        content: vec![StyleAttribute {
+                            key: "width".into(),
+                            value: vec!["100px".into()].into(),
+                        }
+                        .into()]
+                        .into(),
+                    }
+                    .into(),
+                )]
+                .into(),
+            }),
+        ]);
+
+        let json = serde_json::to_string(&sheet).expect("Failed to serialize sheet.");
+        let deserialized: Sheet =
+            serde_json::from_str(&json).expect("Failed to deserialize sheet.");
+
+        assert_eq!(deserialized, sheet);
+        assert_eq!(
+            deserialized.to_style_str(Some("test")),
+            sheet.to_style_str(Some("test"))
+        );
+
+        Ok(())
+    }
 }
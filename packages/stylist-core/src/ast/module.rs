@@ -0,0 +1,158 @@
+use super::{Selector, Sheet, StringFragment, VisitorMut};
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '-' || c == '_'
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Replaces class selectors in `selector` with `&-{class}` and collects the classes.
+///
+/// Elements never have the base class of a css module, so a `&` directly before a class selector
+/// is removed: `&.active` becomes `&-active` rather than `&&-active`.
+fn localize(selector: &str, classes: &mut Vec<String>) -> Option<String> {
+    let mut localized = String::with_capacity(selector.len());
+    let mut changed = false;
+    let mut quote = None;
+    let mut in_attr = false;
+    let mut chars = selector.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match (quote, c) {
+            (Some(m), c) if c == m => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            // Values of attribute selectors are never classes.
+            (None, '[') => in_attr = true,
+            (None, ']') => in_attr = false,
+            (None, '.') if !in_attr && chars.peek().map(|m| is_ident_start(m.1)) == Some(true) => {
+                let rest = &selector[i + 1..];
+                let end = rest.find(|m| !is_ident_char(m)).unwrap_or(rest.len());
+                let class = &rest[..end];
+
+                if !classes.iter().any(|m| m == class) {
+                    classes.push(class.to_string());
+                }
+
+                if localized.ends_with('&') {
+                    localized.pop();
+                }
+
+                localized.push_str("&-");
+                localized.push_str(class);
+                changed = true;
+
+                while chars.peek().map(|m| m.0 < i + 1 + end) == Some(true) {
+                    chars.next();
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        localized.push(c);
+    }
+
+    changed.then_some(localized)
+}
+
+struct Localize {
+    classes: Vec<String>,
+}
+
+impl VisitorMut for Localize {
+    fn visit_selector_mut(&mut self, selector: &mut Selector) {
+        let joined = selector
+            .fragments
+            .iter()
+            .map(|m| m.inner.as_ref())
+            .collect::<String>();
+
+        if let Some(m) = localize(&joined, &mut self.classes) {
+            selector.fragments = vec![StringFragment::from(m)].into();
+        }
+    }
+}
+
+impl Sheet {
+    /// Creates a new stylesheet with local class names, like a CSS module.
+    ///
+    /// Each class selector `.name` is replaced with `&-name`, which is scoped to
+    /// `.{class_name}-name` when the stylesheet is rendered. This gives each class a unique name
+    /// that is not nested in the element the style is applied to. A `&` directly before a class
+    /// selector is removed, so `&.active` selects the same elements as `.active`.
+    ///
+    /// Returns the new stylesheet and the local class names in order of appearance.
+    pub fn localize_classes(&self) -> (Sheet, Vec<String>) {
+        let mut visitor = Localize {
+            classes: Vec::new(),
+        };
+
+        let mut sheet = self.clone();
+        visitor.visit_sheet_mut(&mut sheet);
+
+        (sheet, visitor.classes)
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[test]
+    fn test_localize_classes() {
+        let sheet: Sheet = r#"
+            .title, .footer-link:hover {
+                font-weight: bold;
+            }
+            @media print {
+                .body:not(.title) > a[href=".pdf"] {
+                    display: none;
+                }
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        let (sheet, classes) = sheet.localize_classes();
+
+        assert_eq!(classes, vec!["title", "footer-link", "body"]);
+        assert_eq!(
+            sheet.to_style_str(Some("m")),
+            r#".m-title, .m-footer-link:hover {
+    font-weight: bold;
+}
+@media print {
+    .m-body:not(.m-title) > a[href=".pdf"] {
+        display: none;
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_localize_ampersand() {
+        let sheet: Sheet = r#"
+            &.active, .tab&.active, .tab:hover {
+                color: red;
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        let (sheet, classes) = sheet.localize_classes();
+
+        assert_eq!(classes, vec!["active", "tab"]);
+        assert_eq!(
+            sheet.to_style_str(Some("m")),
+            r#".m-active, .m-tab.m-active, .m-tab:hover {
+    color: red;
+}
+"#
+        );
+    }
+}
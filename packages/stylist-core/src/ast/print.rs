@@ -0,0 +1,227 @@
+//! Prints the AST back as the authored CSS.
+//!
+//! Unlike [`ToStyleStr`](super::ToStyleStr), no scoping is applied: the Current Selector (`&`)
+//! and the nesting of blocks in at-rules are kept as they are, so a stylesheet created by the
+//! parser is parsed into the same stylesheet again.
+
+use std::fmt;
+
+use super::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+};
+
+trait WriteSource {
+    fn write_source(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result;
+}
+
+fn write_padding(f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+    for _ in 0..depth {
+        f.write_str("    ")?;
+    }
+
+    Ok(())
+}
+
+fn write_fragments(f: &mut fmt::Formatter<'_>, frags: &[StringFragment]) -> fmt::Result {
+    for frag in frags.iter() {
+        f.write_str(&frag.inner)?;
+    }
+
+    Ok(())
+}
+
+impl WriteSource for StyleAttribute {
+    fn write_source(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write_padding(f, depth)?;
+        writeln!(f, "{}", self)
+    }
+}
+
+impl WriteSource for Block {
+    fn write_source(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        // A block without condition applies to the current selector, which is how declarations
+        // at the top level of a stylesheet or an at-rule are represented.
+        if self.condition.is_empty() {
+            for content in self.content.iter() {
+                content.write_source(f, depth)?;
+            }
+
+            return Ok(());
+        }
+
+        write_padding(f, depth)?;
+        for (index, sel) in self.condition.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", sel)?;
+        }
+        f.write_str(" {\n")?;
+
+        for content in self.content.iter() {
+            content.write_source(f, depth + 1)?;
+        }
+
+        write_padding(f, depth)?;
+        f.write_str("}\n")
+    }
+}
+
+impl WriteSource for Rule {
+    fn write_source(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write_padding(f, depth)?;
+        write_fragments(f, &self.condition)?;
+        f.write_str(" {\n")?;
+
+        for content in self.content.iter() {
+            content.write_source(f, depth + 1)?;
+        }
+
+        write_padding(f, depth)?;
+        f.write_str("}\n")
+    }
+}
+
+impl WriteSource for RuleBlockContent {
+    fn write_source(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        match self {
+            Self::StyleAttr(ref m) => m.write_source(f, depth),
+            Self::Rule(ref m) => m.write_source(f, depth),
+            Self::Block(ref m) => m.write_source(f, depth),
+        }
+    }
+}
+
+impl WriteSource for ScopeContent {
+    fn write_source(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        match self {
+            Self::Block(ref m) => m.write_source(f, depth),
+            Self::Rule(ref m) => m.write_source(f, depth),
+        }
+    }
+}
+
+impl fmt::Display for StringFragment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_fragments(f, &self.fragments)
+    }
+}
+
+/// Prints the declaration, e.g.: `color: red;`.
+impl fmt::Display for StyleAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.key)?;
+        write_fragments(f, &self.value)?;
+        f.write_str(";")
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_source(f, 0)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_source(f, 0)
+    }
+}
+
+impl fmt::Display for RuleBlockContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_source(f, 0)
+    }
+}
+
+impl fmt::Display for ScopeContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_source(f, 0)
+    }
+}
+
+/// Prints the stylesheet as the authored CSS, without applying any scoping.
+///
+/// For stylesheets created by the parser, parsing the printed stylesheet results in the same
+/// stylesheet. Use [`ToStyleStr`](super::ToStyleStr) to get the CSS that is mounted.
+impl fmt::Display for Sheet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for scope in self.iter() {
+            scope.write_source(f, 0)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip(s: &str) -> String {
+        let sheet: Sheet = s.parse().expect("Failed to parse sheet.");
+        let printed = sheet.to_string();
+        let reparsed: Sheet = printed.parse().expect("Failed to parse printed sheet.");
+
+        assert_eq!(reparsed, sheet, "printed as:\n{}", printed);
+
+        printed
+    }
+
+    #[test]
+    fn test_print() {
+        let printed = assert_round_trip(
+            r#"
+                color: red;
+                &:hover, .inner > span {
+                    background: ${bg};
+                }
+                @media screen and (max-width: 500px) {
+                    color: blue;
+                    .inner {
+                        content: "a;b";
+                    }
+                }
+            "#,
+        );
+
+        assert_eq!(
+            printed,
+            r#"color: red;
+&:hover, .inner > span {
+    background: ${bg};
+}
+@media screen and (max-width: 500px) {
+    color: blue;
+    .inner {
+        content: "a;b";
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_print_nested_rules() {
+        assert_round_trip(
+            r#"
+                @supports (display: grid) {
+                    @media print {
+                        .c { display: grid; }
+                    }
+                }
+                @keyframes move {
+                    from { width: 1px; }
+                    to { width: 2px; }
+                }
+                width: 1px;
+            "#,
+        );
+    }
+}
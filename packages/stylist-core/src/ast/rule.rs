@@ -16,6 +16,7 @@ use super::{RuleBlockContent, StringFragment, StyleContext, ToStyleStr};
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rule {
     pub condition: Cow<'static, [StringFragment]>,
     pub content: Cow<'static, [RuleBlockContent]>,
@@ -31,7 +32,7 @@ impl ToStyleStr for Rule {
         let mut rule_ctx = ctx.with_rule_condition(&cond);
 
         // keyframes should always be printed as they contain a global name.
-        let always_print = cond.starts_with("@keyframes");
+        let always_print = cond.starts_with("@keyframes") || cond.starts_with("@-webkit-keyframes");
         if always_print {
             rule_ctx.start(w);
         }
@@ -3,6 +3,7 @@ use crate::bow::Bow;
 
 /// The content of a [`Rule`] or a [`Block`]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RuleBlockContent {
     StyleAttr(StyleAttribute),
     Rule(Bow<'static, Rule>),
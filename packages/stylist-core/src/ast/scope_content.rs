@@ -21,6 +21,7 @@ use super::{Block, Rule, StyleContext, ToStyleStr};
 /// /* END Scope */
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScopeContent {
     Block(Block),
     Rule(Rule),
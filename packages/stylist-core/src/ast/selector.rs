@@ -9,6 +9,7 @@ use super::{StringFragment, StyleContext, ToStyleStr};
 /// div[attr="val"].my-class#some-id
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Selector {
     pub fragments: Cow<'static, [StringFragment]>,
 }
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::ops::Deref;
 use std::sync::Arc;
 
+use super::interpolate::Interpolate;
 use super::{ScopeContent, StyleContext, ToStyleStr};
 
 /// The top node of a stylesheet.
@@ -22,6 +23,41 @@ impl Sheet {
     pub fn new() -> Self {
         Self(Arc::new(Cow::Borrowed(&[])))
     }
+
+    /// Returns a mutable reference to the scopes of this stylesheet.
+    ///
+    /// The scopes are cloned if they are shared with another stylesheet.
+    pub fn to_mut(&mut self) -> &mut Vec<ScopeContent> {
+        Arc::make_mut(&mut self.0).to_mut()
+    }
+
+    /// Appends the scopes of `other` to this stylesheet.
+    ///
+    /// The scopes are appended in order, so declarations of `other` take precedence over
+    /// declarations of this stylesheet with the same specificity. Both stylesheets are scoped
+    /// to the same class when rendered, so a Current Selector (`&`) in `other` refers to the
+    /// same element as in this stylesheet.
+    pub fn extend(&mut self, other: &Sheet) {
+        if self.is_empty() {
+            *self = other.clone();
+        } else if !other.is_empty() {
+            self.to_mut().extend(other.iter().cloned());
+        }
+    }
+
+    /// Creates a new stylesheet with all `${name}` placeholders substituted.
+    ///
+    /// `args` is called with the name of each placeholder and should return its value.
+    /// Like the string literal syntax of the macros, `$${` can be used to escape a `${`.
+    ///
+    /// Returns [`Error::Interpolation`](crate::Error::Interpolation) if `args` returns `None`
+    /// for any placeholder.
+    pub fn interpolate<'a, F>(&self, args: F) -> crate::Result<Self>
+    where
+        F: Fn(&str) -> Option<Cow<'a, str>>,
+    {
+        Interpolate::interpolate(self, &args)
+    }
 }
 
 impl From<Vec<ScopeContent>> for Sheet {
@@ -89,3 +125,23 @@ mod feat_parser {
         }
     }
 }
+
+#[cfg_attr(documenting, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+mod feat_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl Serialize for Sheet {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Sheet {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Vec::<ScopeContent>::deserialize(deserializer).map(Sheet::from)
+        }
+    }
+}
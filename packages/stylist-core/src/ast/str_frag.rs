@@ -4,6 +4,7 @@ use super::{StyleContext, ToStyleStr};
 
 /// A String Fragment
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringFragment {
     pub inner: Cow<'static, str>,
 }
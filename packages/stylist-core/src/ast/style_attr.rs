@@ -7,6 +7,7 @@ use super::{StringFragment, StyleContext, ToStyleStr};
 ///
 /// E.g.: `color: red`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StyleAttribute {
     pub key: Cow<'static, str>,
     pub value: Cow<'static, [StringFragment]>,
@@ -0,0 +1,817 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{StringFragment, StyleAttribute};
+use crate::{Error, Result};
+
+/// A typed representation of the value of a [`StyleAttribute`].
+///
+/// This is an optional layer on top of the [`StringFragment`]s of a style attribute and is not
+/// used when generating stylesheets. It can be used by tooling to validate, transform and minify
+/// values.
+///
+/// E.g.: `1px solid rgba(0, 0, 0, 0.5)`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// An identifier, e.g.: `solid` or `!important`.
+    ///
+    /// This also holds operators in math functions (`+`, `-`, `*`) and unquoted `url()` arguments,
+    /// which are kept verbatim.
+    Keyword(Cow<'static, str>),
+    /// A number without a unit, e.g.: `1.5`.
+    Number(f64),
+    /// A percentage, e.g.: `50%`.
+    Percentage(f64),
+    /// A length, e.g.: `10px`.
+    Length(Length),
+    /// A dimension that is not a length, e.g.: `90deg` or `1s`.
+    Dimension(f64, Cow<'static, str>),
+    /// A color, e.g.: `#ff0000` or `rgba(0, 0, 0, 0.5)`.
+    Color(Color),
+    /// A quoted string, e.g.: `"\e600"`.
+    ///
+    /// The content is kept as written between the quotes, including escape sequences.
+    String(Cow<'static, str>),
+    /// A function, e.g.: `translateX(10px)`.
+    Function(Function),
+    /// A list of values, e.g.: `1px solid red`.
+    List(List),
+}
+
+/// Units of a [`Length`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    Px,
+    Cm,
+    Mm,
+    Q,
+    In,
+    Pt,
+    Pc,
+    Em,
+    Rem,
+    Ex,
+    Ch,
+    Vw,
+    Vh,
+    Vmin,
+    Vmax,
+}
+
+/// A length with a unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Length {
+    pub value: f64,
+    pub unit: LengthUnit,
+}
+
+/// A color in the sRGB color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    /// Alpha channel between `0.0` and `1.0`.
+    pub alpha: f64,
+}
+
+/// A function call with comma separated arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: Cow<'static, str>,
+    pub args: Vec<Value>,
+}
+
+/// The separator between the items of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    Space,
+    Comma,
+    Slash,
+}
+
+/// A list of values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct List {
+    pub separator: Separator,
+    pub items: Vec<Value>,
+}
+
+impl LengthUnit {
+    const ALL: [LengthUnit; 15] = [
+        Self::Px,
+        Self::Cm,
+        Self::Mm,
+        Self::Q,
+        Self::In,
+        Self::Pt,
+        Self::Pc,
+        Self::Em,
+        Self::Rem,
+        Self::Ex,
+        Self::Ch,
+        Self::Vw,
+        Self::Vh,
+        Self::Vmin,
+        Self::Vmax,
+    ];
+
+    /// Returns the unit as written in CSS.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Px => "px",
+            Self::Cm => "cm",
+            Self::Mm => "mm",
+            Self::Q => "q",
+            Self::In => "in",
+            Self::Pt => "pt",
+            Self::Pc => "pc",
+            Self::Em => "em",
+            Self::Rem => "rem",
+            Self::Ex => "ex",
+            Self::Ch => "ch",
+            Self::Vw => "vw",
+            Self::Vh => "vh",
+            Self::Vmin => "vmin",
+            Self::Vmax => "vmax",
+        }
+    }
+
+    /// Returns the unit for a CSS unit name, case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .find(|m| m.as_str().eq_ignore_ascii_case(name))
+            .copied()
+    }
+
+    /// Returns the number of pixels in one of this unit, if this is an absolute unit.
+    pub fn px_per_unit(&self) -> Option<f64> {
+        match self {
+            Self::Px => Some(1.0),
+            Self::Cm => Some(96.0 / 2.54),
+            Self::Mm => Some(96.0 / 25.4),
+            Self::Q => Some(96.0 / 101.6),
+            Self::In => Some(96.0),
+            Self::Pt => Some(96.0 / 72.0),
+            Self::Pc => Some(16.0),
+            _ => None,
+        }
+    }
+}
+
+impl Length {
+    /// Creates a length.
+    pub fn new(value: f64, unit: LengthUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Converts this length into another unit.
+    ///
+    /// Returns `None` if either unit is not absolute.
+    pub fn convert(&self, unit: LengthUnit) -> Option<Self> {
+        let px = self.value * self.unit.px_per_unit()?;
+
+        Some(Self::new(px / unit.px_per_unit()?, unit))
+    }
+}
+
+impl Color {
+    /// Creates an opaque color.
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 1.0)
+    }
+
+    /// Creates a color with an alpha channel.
+    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Parses a hex color without the leading `#`.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        if !hex.chars().all(|m| m.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+        let short = |c: char| channel(&c.to_string().repeat(2));
+        let mut chars = hex.chars();
+
+        match hex.len() {
+            3 | 4 => {
+                let red = short(chars.next()?)?;
+                let green = short(chars.next()?)?;
+                let blue = short(chars.next()?)?;
+                let alpha = chars.next().map(short).unwrap_or(Some(255))?;
+                Some(Self::rgba(red, green, blue, f64::from(alpha) / 255.0))
+            }
+            6 | 8 => {
+                let red = channel(&hex[0..2])?;
+                let green = channel(&hex[2..4])?;
+                let blue = channel(&hex[4..6])?;
+                let alpha = hex.get(6..8).map(channel).unwrap_or(Some(255))?;
+                Some(Self::rgba(red, green, blue, f64::from(alpha) / 255.0))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of this color with a different alpha channel.
+    pub fn with_alpha(&self, alpha: f64) -> Self {
+        Self::rgba(self.red, self.green, self.blue, alpha)
+    }
+
+    /// Returns `true` if the color is fully opaque.
+    pub fn is_opaque(&self) -> bool {
+        (self.alpha - 1.0).abs() < f64::EPSILON
+    }
+
+    fn from_function(name: &str, args: &[Value]) -> Option<Self> {
+        if !(name.eq_ignore_ascii_case("rgb") || name.eq_ignore_ascii_case("rgba")) {
+            return None;
+        }
+
+        let channel = |m: &Value| match m {
+            Value::Number(n) if (0.0..=255.0).contains(n) => Some(n.round() as u8),
+            Value::Percentage(n) if (0.0..=100.0).contains(n) => {
+                Some((n * 255.0 / 100.0).round() as u8)
+            }
+            _ => None,
+        };
+
+        let alpha = |m: &Value| match m {
+            Value::Number(n) => Some(*n),
+            Value::Percentage(n) => Some(n / 100.0),
+            _ => None,
+        };
+
+        match args {
+            [r, g, b] => Some(Self::rgb(channel(r)?, channel(g)?, channel(b)?)),
+            [r, g, b, a] => Some(Self::rgba(channel(r)?, channel(g)?, channel(b)?, alpha(a)?)),
+            _ => None,
+        }
+    }
+}
+
+impl Value {
+    /// Parses a value from its CSS representation.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut parser = ValueParser { rest: s };
+        let value = parser.list(None)?;
+
+        if !parser.rest.trim().is_empty() {
+            return Err(parser.error("unexpected input"));
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a value from a list of [`StringFragment`]s.
+    ///
+    /// Fails if any fragment contains an uninterpolated `${}` placeholder.
+    pub fn from_fragments(fragments: &[StringFragment]) -> Result<Self> {
+        let s: String = fragments.iter().map(|m| m.inner.as_ref()).collect();
+
+        if s.contains("${") {
+            return Err(Error::Value {
+                reason: format!("uninterpolated placeholder in value: {}", s),
+            });
+        }
+
+        Self::parse(&s)
+    }
+
+    /// Returns the items of this value if it is a [`List`] or the value itself otherwise.
+    pub fn items(&self) -> &[Value] {
+        match self {
+            Self::List(m) => &m.items,
+            m => std::slice::from_ref(m),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl From<Value> for StringFragment {
+    fn from(v: Value) -> Self {
+        v.to_string().into()
+    }
+}
+
+impl From<Length> for Value {
+    fn from(l: Length) -> Self {
+        Self::Length(l)
+    }
+}
+
+impl From<Color> for Value {
+    fn from(c: Color) -> Self {
+        Self::Color(c)
+    }
+}
+
+impl StyleAttribute {
+    /// Parses the value of this attribute into a typed [`Value`].
+    pub fn typed_value(&self) -> Result<Value> {
+        Value::from_fragments(&self.value)
+    }
+
+    /// Creates a style attribute from a key and a typed [`Value`].
+    pub fn from_typed<K>(key: K, value: Value) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+    {
+        Self {
+            key: key.into(),
+            value: vec![value.into()].into(),
+        }
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.value, self.unit)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_opaque() {
+            return write!(
+                f,
+                "rgba({}, {}, {}, {})",
+                self.red, self.green, self.blue, self.alpha
+            );
+        }
+
+        let channels = [self.red, self.green, self.blue];
+        // Use the shortest form if every channel is made of 2 identical digits.
+        if channels.iter().all(|m| m >> 4 == m & 0xf) {
+            write!(f, "#")?;
+            for c in channels.iter() {
+                write!(f, "{:x}", c & 0xf)?;
+            }
+            Ok(())
+        } else {
+            write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+        }
+    }
+}
+
+impl fmt::Display for Separator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Space => " ",
+            Self::Comma => ", ",
+            Self::Slash => " / ",
+        })
+    }
+}
+
+impl fmt::Display for List {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+                self.separator.fmt(f)?;
+            }
+            item.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (index, arg) in self.args.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            arg.fmt(f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Writes `s` in double quotes, escaping double quotes that are not escaped yet, such as the
+/// ones of a string that was quoted with single quotes.
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+
+    let mut escaped = false;
+    for c in s.chars() {
+        if c == '"' && !escaped {
+            f.write_str("\\")?;
+        }
+
+        escaped = !escaped && c == '\\';
+        write!(f, "{}", c)?;
+    }
+
+    f.write_str("\"")
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyword(m) => f.write_str(m),
+            Self::Number(m) => write!(f, "{}", m),
+            Self::Percentage(m) => write!(f, "{}%", m),
+            Self::Length(m) => m.fmt(f),
+            Self::Dimension(value, unit) => write!(f, "{}{}", value, unit),
+            Self::Color(m) => m.fmt(f),
+            Self::String(m) => write_string(f, m),
+            Self::Function(m) => m.fmt(f),
+            Self::List(m) => m.fmt(f),
+        }
+    }
+}
+
+/// A small hand written parser for values.
+///
+/// The nom based parser does not look into values, so this does not depend on feature `parser`.
+struct ValueParser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> ValueParser<'a> {
+    fn error(&self, reason: &str) -> Error {
+        Error::Value {
+            reason: format!("{} at: {}", reason, self.rest),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    fn bump(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let len = self
+            .rest
+            .char_indices()
+            .find(|(_, c)| !f(*c))
+            .map(|(i, _)| i)
+            .unwrap_or_else(|| self.rest.len());
+        self.bump(len)
+    }
+
+    fn is_ident_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+    }
+
+    fn starts_ident(s: &str) -> bool {
+        let mut chars = s.chars();
+        match chars.next() {
+            Some('-') => {
+                matches!(chars.next(), Some(c) if c == '-' || c == '_' || c.is_ascii_alphabetic() || !c.is_ascii())
+            }
+            Some(c) => c == '_' || c.is_ascii_alphabetic() || !c.is_ascii(),
+            None => false,
+        }
+    }
+
+    fn starts_number(s: &str) -> bool {
+        let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let s = s.strip_prefix('.').unwrap_or(s);
+        s.starts_with(|c: char| c.is_ascii_digit())
+    }
+
+    /// Parses a list of values until the end of input or `end` is reached.
+    ///
+    /// Commas bind weakest, followed by slashes and spaces.
+    fn list(&mut self, end: Option<char>) -> Result<Value> {
+        let mut comma_groups: Vec<Vec<Vec<Value>>> = vec![vec![vec![]]];
+
+        loop {
+            self.take_while(char::is_whitespace);
+
+            match self.peek() {
+                None => break,
+                Some(c) if Some(c) == end => break,
+                Some(',') => {
+                    self.bump(1);
+                    comma_groups.push(vec![vec![]]);
+                }
+                Some('/') => {
+                    self.bump(1);
+                    comma_groups.last_mut().unwrap().push(vec![]);
+                }
+                Some(_) => {
+                    let value = self.component()?;
+                    comma_groups
+                        .last_mut()
+                        .and_then(|m| m.last_mut())
+                        .unwrap()
+                        .push(value);
+                }
+            }
+        }
+
+        fn group(separator: Separator, mut items: Vec<Value>) -> Value {
+            if items.len() == 1 {
+                items.remove(0)
+            } else {
+                Value::List(List { separator, items })
+            }
+        }
+
+        let mut items = Vec::new();
+        for slash_groups in comma_groups {
+            let mut slash_items = Vec::new();
+            for space_items in slash_groups {
+                if space_items.is_empty() {
+                    return Err(self.error("expected a value"));
+                }
+                slash_items.push(group(Separator::Space, space_items));
+            }
+            items.push(group(Separator::Slash, slash_items));
+        }
+
+        Ok(group(Separator::Comma, items))
+    }
+
+    fn component(&mut self) -> Result<Value> {
+        let c = self.peek().ok_or_else(|| self.error("expected a value"))?;
+
+        if c == '"' || c == '\'' {
+            return self.string(c);
+        }
+
+        if c == '#' {
+            self.bump(1);
+            let hex = self.take_while(Self::is_ident_char);
+            return Color::from_hex(hex)
+                .map(Value::Color)
+                .ok_or_else(|| self.error("invalid hex color"));
+        }
+
+        if Self::starts_number(self.rest) {
+            return self.numeric();
+        }
+
+        if Self::starts_ident(self.rest) {
+            let ident = self.take_while(Self::is_ident_char);
+
+            if self.peek() != Some('(') {
+                return Ok(Value::Keyword(ident.to_string().into()));
+            }
+            self.bump(1);
+
+            return self.function(ident);
+        }
+
+        if c == '!' {
+            self.bump(1);
+            let ident = self.take_while(Self::is_ident_char);
+            if ident.is_empty() {
+                return Err(self.error("expected an identifier after '!'"));
+            }
+            return Ok(Value::Keyword(format!("!{}", ident).into()));
+        }
+
+        if c == '+' || c == '-' || c == '*' {
+            let op = self.bump(1);
+            return Ok(Value::Keyword(op.to_string().into()));
+        }
+
+        Err(self.error("unexpected character"))
+    }
+
+    fn string(&mut self, quote: char) -> Result<Value> {
+        self.bump(1);
+
+        let mut escaped = false;
+        let len = self.rest.char_indices().find(|(_, c)| {
+            let is_end = !escaped && *c == quote;
+            escaped = !escaped && *c == '\\';
+            is_end
+        });
+
+        match len {
+            Some((len, _)) => {
+                let inner = self.bump(len);
+                self.bump(1);
+                Ok(Value::String(inner.to_string().into()))
+            }
+            None => Err(self.error("unterminated string")),
+        }
+    }
+
+    fn numeric(&mut self) -> Result<Value> {
+        let start = self.rest;
+        let mut len = 0;
+
+        if start.starts_with(['+', '-']) {
+            len += 1;
+        }
+        len += start[len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(start.len() - len);
+        if start[len..].starts_with('.') {
+            len += 1;
+            len += start[len..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(start.len() - len);
+        }
+
+        let number = self.bump(len);
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| self.error("invalid number"))?;
+
+        if self.peek() == Some('%') {
+            self.bump(1);
+            return Ok(Value::Percentage(number));
+        }
+
+        let unit = self.take_while(Self::is_ident_char);
+        if unit.is_empty() {
+            return Ok(Value::Number(number));
+        }
+
+        Ok(match LengthUnit::from_name(unit) {
+            Some(unit) => Value::Length(Length::new(number, unit)),
+            None => Value::Dimension(number, unit.to_string().into()),
+        })
+    }
+
+    fn function(&mut self, name: &str) -> Result<Value> {
+        // Unquoted urls may contain any character and are kept as is.
+        if name.eq_ignore_ascii_case("url") {
+            let rest = self.rest.trim_start();
+            if !rest.starts_with(['"', '\'']) {
+                let url = self.take_while(|c| c != ')');
+                if self.peek() != Some(')') {
+                    return Err(self.error("expected ')'"));
+                }
+                self.bump(1);
+
+                return Ok(Value::Function(Function {
+                    name: name.to_string().into(),
+                    args: vec![Value::Keyword(url.trim().to_string().into())],
+                }));
+            }
+        }
+
+        let args = if self.rest.trim_start().starts_with(')') {
+            self.take_while(char::is_whitespace);
+            vec![]
+        } else {
+            match self.list(Some(')'))? {
+                Value::List(List {
+                    separator: Separator::Comma,
+                    items,
+                }) => items,
+                m => vec![m],
+            }
+        };
+
+        if self.peek() != Some(')') {
+            return Err(self.error("expected ')'"));
+        }
+        self.bump(1);
+
+        if let Some(m) = Color::from_function(name, &args) {
+            return Ok(Value::Color(m));
+        }
+
+        Ok(Value::Function(Function {
+            name: name.to_string().into(),
+            args,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(s: &str) -> String {
+        Value::parse(s).expect("Failed to parse value.").to_string()
+    }
+
+    #[test]
+    fn test_simple_values() {
+        assert_eq!(Value::parse("red").unwrap(), Value::Keyword("red".into()));
+        assert_eq!(Value::parse("1.5").unwrap(), Value::Number(1.5));
+        assert_eq!(Value::parse("-50%").unwrap(), Value::Percentage(-50.0));
+        assert_eq!(
+            Value::parse("10px").unwrap(),
+            Value::Length(Length::new(10.0, LengthUnit::Px))
+        );
+        assert_eq!(
+            Value::parse(".5s").unwrap(),
+            Value::Dimension(0.5, "s".into())
+        );
+        assert_eq!(
+            Value::parse("#f00").unwrap(),
+            Value::Color(Color::rgb(255, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let value = Value::parse("1px solid rgba(0, 0, 0, 0.5)").unwrap();
+
+        assert_eq!(
+            value,
+            Value::List(List {
+                separator: Separator::Space,
+                items: vec![
+                    Length::new(1.0, LengthUnit::Px).into(),
+                    Value::Keyword("solid".into()),
+                    Color::rgba(0, 0, 0, 0.5).into(),
+                ],
+            })
+        );
+
+        assert_eq!(
+            round_trip("12px/1.5 \"Helvetica Neue\", sans-serif"),
+            "12px / 1.5 \"Helvetica Neue\", sans-serif"
+        );
+    }
+
+    #[test]
+    fn test_functions() {
+        assert_eq!(
+            round_trip("translateX( 10px ) rotate(90deg)"),
+            "translateX(10px) rotate(90deg)"
+        );
+        assert_eq!(round_trip("calc(100% - 10px)"), "calc(100% - 10px)");
+        assert_eq!(
+            round_trip("url(https://example.com/a.png)"),
+            "url(https://example.com/a.png)"
+        );
+        assert_eq!(
+            round_trip("rgb(255, 255, 255) !important"),
+            "#fff !important"
+        );
+    }
+
+    #[test]
+    fn test_strings() {
+        assert_eq!(round_trip(r#"'a"b'"#), r#""a\"b""#);
+        assert_eq!(round_trip(r#"'it\'s'"#), r#""it\'s""#);
+        assert_eq!(round_trip(r#""a\"b""#), r#""a\"b""#);
+        assert_eq!(round_trip(r#""\e600""#), r#""\e600""#);
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(Value::parse("#ggg").is_err());
+        assert!(Value::parse("calc(1px").is_err());
+        assert!(Value::parse("1px,").is_err());
+    }
+
+    #[test]
+    fn test_length_convert() {
+        let length = Length::new(1.0, LengthUnit::In)
+            .convert(LengthUnit::Px)
+            .unwrap();
+        assert_eq!(length, Length::new(96.0, LengthUnit::Px));
+
+        assert!(Length::new(1.0, LengthUnit::Em)
+            .convert(LengthUnit::Px)
+            .is_none());
+    }
+
+    #[test]
+    fn test_style_attr_typed_value() {
+        let attr = StyleAttribute {
+            key: "color".into(),
+            value: vec!["#".into(), "ff0000".into()].into(),
+        };
+
+        let color = match attr.typed_value().unwrap() {
+            Value::Color(m) => m,
+            _ => panic!("expected a color"),
+        };
+
+        let attr = StyleAttribute::from_typed("color", color.with_alpha(0.5).into());
+        assert_eq!(attr.value[0].inner, "rgba(255, 0, 0, 0.5)");
+    }
+}
@@ -0,0 +1,367 @@
+use super::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+};
+
+/// A trait to traverse a stylesheet.
+///
+/// Each method is called when a node of the corresponding type is reached. The default
+/// implementations visit all children of the node. When overriding a method, call
+/// `visit_children` on the node to continue the traversal into its children.
+///
+/// ```
+/// use stylist_core::ast::{Sheet, StyleAttribute, Visitor};
+///
+/// #[derive(Default)]
+/// struct CountAttrs(usize);
+///
+/// impl Visitor for CountAttrs {
+///     fn visit_style_attr(&mut self, _attr: &StyleAttribute) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut counter = CountAttrs::default();
+/// counter.visit_sheet(&Sheet::new());
+/// assert_eq!(counter.0, 0);
+/// ```
+pub trait Visitor {
+    fn visit_sheet(&mut self, sheet: &Sheet) {
+        sheet.visit_children(self);
+    }
+
+    fn visit_scope_content(&mut self, scope: &ScopeContent) {
+        scope.visit_children(self);
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        block.visit_children(self);
+    }
+
+    fn visit_rule(&mut self, rule: &Rule) {
+        rule.visit_children(self);
+    }
+
+    fn visit_rule_block_content(&mut self, content: &RuleBlockContent) {
+        content.visit_children(self);
+    }
+
+    fn visit_selector(&mut self, selector: &Selector) {
+        selector.visit_children(self);
+    }
+
+    fn visit_style_attr(&mut self, attr: &StyleAttribute) {
+        attr.visit_children(self);
+    }
+
+    fn visit_string_fragment(&mut self, _fragment: &StringFragment) {}
+}
+
+/// A trait to traverse and modify a stylesheet in place.
+///
+/// This works like [`Visitor`], but nodes are passed by mutable reference. Shared nodes are
+/// cloned before they are visited, so other stylesheets are never affected.
+pub trait VisitorMut {
+    fn visit_sheet_mut(&mut self, sheet: &mut Sheet) {
+        sheet.visit_children_mut(self);
+    }
+
+    fn visit_scope_content_mut(&mut self, scope: &mut ScopeContent) {
+        scope.visit_children_mut(self);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        block.visit_children_mut(self);
+    }
+
+    fn visit_rule_mut(&mut self, rule: &mut Rule) {
+        rule.visit_children_mut(self);
+    }
+
+    fn visit_rule_block_content_mut(&mut self, content: &mut RuleBlockContent) {
+        content.visit_children_mut(self);
+    }
+
+    fn visit_selector_mut(&mut self, selector: &mut Selector) {
+        selector.visit_children_mut(self);
+    }
+
+    fn visit_style_attr_mut(&mut self, attr: &mut StyleAttribute) {
+        attr.visit_children_mut(self);
+    }
+
+    fn visit_string_fragment_mut(&mut self, _fragment: &mut StringFragment) {}
+}
+
+impl Sheet {
+    /// Visits all scopes of this stylesheet.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        for scope in self.iter() {
+            v.visit_scope_content(scope);
+        }
+    }
+
+    /// Visits all scopes of this stylesheet mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        for scope in self.to_mut().iter_mut() {
+            v.visit_scope_content_mut(scope);
+        }
+    }
+
+    /// Creates a new stylesheet with `f` applied to every style attribute.
+    ///
+    /// Use [`VisitorMut`] for transforms that need to modify other nodes.
+    pub fn map<F>(&self, f: F) -> Self
+    where
+        F: FnMut(&mut StyleAttribute),
+    {
+        struct MapAttrs<F>(F);
+
+        impl<F: FnMut(&mut StyleAttribute)> VisitorMut for MapAttrs<F> {
+            fn visit_style_attr_mut(&mut self, attr: &mut StyleAttribute) {
+                (self.0)(attr);
+            }
+        }
+
+        let mut sheet = self.clone();
+        MapAttrs(f).visit_sheet_mut(&mut sheet);
+
+        sheet
+    }
+
+    /// Folds every style attribute of this stylesheet into an accumulator, in source order.
+    ///
+    /// Use [`Visitor`] for traversals that need to inspect other nodes.
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &StyleAttribute) -> B,
+    {
+        struct FoldAttrs<B, F> {
+            acc: Option<B>,
+            f: F,
+        }
+
+        impl<B, F: FnMut(B, &StyleAttribute) -> B> Visitor for FoldAttrs<B, F> {
+            fn visit_style_attr(&mut self, attr: &StyleAttribute) {
+                if let Some(m) = self.acc.take() {
+                    self.acc = Some((self.f)(m, attr));
+                }
+            }
+        }
+
+        let mut folder = FoldAttrs { acc: Some(init), f };
+        folder.visit_sheet(self);
+
+        folder
+            .acc
+            .expect("the accumulator is always restored after each call")
+    }
+}
+
+impl ScopeContent {
+    /// Visits the block or rule of this scope.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        match self {
+            Self::Block(ref m) => v.visit_block(m),
+            Self::Rule(ref m) => v.visit_rule(m),
+        }
+    }
+
+    /// Visits the block or rule of this scope mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        match self {
+            Self::Block(ref mut m) => v.visit_block_mut(m),
+            Self::Rule(ref mut m) => v.visit_rule_mut(m),
+        }
+    }
+}
+
+impl Block {
+    /// Visits the selectors, then the content of this block.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        for selector in self.condition.iter() {
+            v.visit_selector(selector);
+        }
+
+        for content in self.content.iter() {
+            v.visit_rule_block_content(content);
+        }
+    }
+
+    /// Visits the selectors, then the content of this block mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        for selector in self.condition.to_mut().iter_mut() {
+            v.visit_selector_mut(selector);
+        }
+
+        for content in self.content.to_mut().iter_mut() {
+            v.visit_rule_block_content_mut(content);
+        }
+    }
+}
+
+impl Rule {
+    /// Visits the condition, then the content of this rule.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        for fragment in self.condition.iter() {
+            v.visit_string_fragment(fragment);
+        }
+
+        for content in self.content.iter() {
+            v.visit_rule_block_content(content);
+        }
+    }
+
+    /// Visits the condition, then the content of this rule mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        for fragment in self.condition.to_mut().iter_mut() {
+            v.visit_string_fragment_mut(fragment);
+        }
+
+        for content in self.content.to_mut().iter_mut() {
+            v.visit_rule_block_content_mut(content);
+        }
+    }
+}
+
+impl RuleBlockContent {
+    /// Visits the style attribute, rule or block of this content.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        match self {
+            Self::StyleAttr(ref m) => v.visit_style_attr(m),
+            Self::Rule(ref m) => v.visit_rule(m),
+            Self::Block(ref m) => v.visit_block(m),
+        }
+    }
+
+    /// Visits the style attribute, rule or block of this content mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        match self {
+            Self::StyleAttr(ref mut m) => v.visit_style_attr_mut(m),
+            Self::Rule(ref mut m) => v.visit_rule_mut(m.to_mut()),
+            Self::Block(ref mut m) => v.visit_block_mut(m.to_mut()),
+        }
+    }
+}
+
+impl Selector {
+    /// Visits the fragments of this selector.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        for fragment in self.fragments.iter() {
+            v.visit_string_fragment(fragment);
+        }
+    }
+
+    /// Visits the fragments of this selector mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        for fragment in self.fragments.to_mut().iter_mut() {
+            v.visit_string_fragment_mut(fragment);
+        }
+    }
+}
+
+impl StyleAttribute {
+    /// Visits the value fragments of this style attribute.
+    pub fn visit_children<V: Visitor + ?Sized>(&self, v: &mut V) {
+        for fragment in self.value.iter() {
+            v.visit_string_fragment(fragment);
+        }
+    }
+
+    /// Visits the value fragments of this style attribute mutably.
+    pub fn visit_children_mut<V: VisitorMut + ?Sized>(&mut self, v: &mut V) {
+        for fragment in self.value.to_mut().iter_mut() {
+            v.visit_string_fragment_mut(fragment);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    fn sheet() -> Sheet {
+        r#"
+            color: red;
+
+            .inner {
+                padding: 8px;
+            }
+
+            @media screen and (max-width: 500px) {
+                .inner {
+                    padding: 4px;
+                }
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.")
+    }
+
+    #[test]
+    fn test_visitor() {
+        #[derive(Default)]
+        struct Selectors(Vec<String>);
+
+        impl Visitor for Selectors {
+            fn visit_selector(&mut self, selector: &Selector) {
+                self.0.push(selector.to_style_str(None));
+            }
+        }
+
+        let mut selectors = Selectors::default();
+        selectors.visit_sheet(&sheet());
+
+        assert_eq!(selectors.0, vec![".inner", ".inner"]);
+    }
+
+    #[test]
+    fn test_visitor_mut() {
+        struct RenameInner;
+
+        impl VisitorMut for RenameInner {
+            fn visit_string_fragment_mut(&mut self, fragment: &mut StringFragment) {
+                if fragment.inner.contains(".inner") {
+                    *fragment = fragment.inner.replace(".inner", ".outer").into();
+                }
+            }
+        }
+
+        let original = sheet();
+        let mut renamed = original.clone();
+        RenameInner.visit_sheet_mut(&mut renamed);
+
+        assert_ne!(original, renamed);
+        assert_eq!(
+            renamed.to_style_str(Some("test")),
+            r#".test {
+    color: red;
+}
+.test .outer {
+    padding: 8px;
+}
+@media screen and (max-width: 500px) {
+    .test .outer {
+        padding: 4px;
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_map_fold() {
+        let mapped = sheet().map(|attr| {
+            if attr.key == "padding" {
+                attr.key = "margin".into();
+            }
+        });
+
+        let keys = mapped.fold(Vec::new(), |mut keys, attr| {
+            keys.push(attr.key.to_string());
+            keys
+        });
+
+        assert_eq!(keys, vec!["color", "margin", "margin"]);
+    }
+}
@@ -0,0 +1,329 @@
+//! Prefix data, derived from caniuse.com.
+//!
+//! Each entry lists the first version of a browser that supports the feature without a vendor
+//! prefix. Browsers that are not listed never needed a prefix.
+
+use super::{Browser::*, Prefix::*, Prefixes, Support, Version};
+
+const ALWAYS: Version = Version::new(u32::MAX, 0);
+
+const ANIMATION: Prefixes = &[(
+    Webkit,
+    &[
+        (Chrome, Version::new(43, 0)),
+        (Safari, Version::new(9, 0)),
+        (IosSafari, Version::new(9, 0)),
+    ],
+)];
+
+const MASK: Prefixes = &[(
+    Webkit,
+    &[
+        (Chrome, Version::new(120, 0)),
+        (Edge, Version::new(120, 0)),
+        (Safari, Version::new(15, 4)),
+        (IosSafari, Version::new(15, 4)),
+    ],
+)];
+
+const TEXT_EMPHASIS: Prefixes = &[(
+    Webkit,
+    &[
+        (Chrome, Version::new(99, 0)),
+        (Edge, Version::new(99, 0)),
+        (Safari, Version::new(7, 0)),
+        (IosSafari, Version::new(7, 0)),
+    ],
+)];
+
+const TRANSFORM: Prefixes = &[(
+    Webkit,
+    &[
+        (Chrome, Version::new(36, 0)),
+        (Safari, Version::new(9, 0)),
+        (IosSafari, Version::new(9, 0)),
+    ],
+)];
+
+const TRANSITION: Prefixes = &[(
+    Webkit,
+    &[
+        (Chrome, Version::new(26, 0)),
+        (Safari, Version::new(6, 1)),
+        (IosSafari, Version::new(7, 0)),
+    ],
+)];
+
+/// Properties that need a prefixed declaration, sorted by name.
+pub(super) const PROPERTIES: &[(&str, Prefixes)] = &[
+    ("animation", ANIMATION),
+    ("animation-delay", ANIMATION),
+    ("animation-direction", ANIMATION),
+    ("animation-duration", ANIMATION),
+    ("animation-fill-mode", ANIMATION),
+    ("animation-iteration-count", ANIMATION),
+    ("animation-name", ANIMATION),
+    ("animation-play-state", ANIMATION),
+    ("animation-timing-function", ANIMATION),
+    (
+        "appearance",
+        &[
+            (
+                Webkit,
+                &[
+                    (Chrome, Version::new(84, 0)),
+                    (Edge, Version::new(84, 0)),
+                    (Safari, Version::new(15, 4)),
+                    (IosSafari, Version::new(15, 4)),
+                ],
+            ),
+            (Moz, &[(Firefox, Version::new(80, 0))]),
+        ],
+    ),
+    (
+        "backdrop-filter",
+        &[(
+            Webkit,
+            &[
+                (Safari, Version::new(18, 0)),
+                (IosSafari, Version::new(18, 0)),
+            ],
+        )],
+    ),
+    (
+        "backface-visibility",
+        &[(
+            Webkit,
+            &[
+                (Chrome, Version::new(36, 0)),
+                (Safari, Version::new(15, 4)),
+                (IosSafari, Version::new(15, 4)),
+            ],
+        )],
+    ),
+    (
+        "box-decoration-break",
+        &[(
+            Webkit,
+            &[
+                (Chrome, Version::new(130, 0)),
+                (Edge, Version::new(130, 0)),
+                (Safari, ALWAYS),
+                (IosSafari, ALWAYS),
+            ],
+        )],
+    ),
+    (
+        "clip-path",
+        &[(
+            Webkit,
+            &[
+                (Chrome, Version::new(55, 0)),
+                (Safari, Version::new(13, 1)),
+                (IosSafari, Version::new(13, 4)),
+            ],
+        )],
+    ),
+    (
+        "filter",
+        &[(
+            Webkit,
+            &[
+                (Chrome, Version::new(53, 0)),
+                (Safari, Version::new(9, 1)),
+                (IosSafari, Version::new(9, 3)),
+            ],
+        )],
+    ),
+    (
+        "hyphens",
+        &[(
+            Webkit,
+            &[
+                (Safari, Version::new(17, 0)),
+                (IosSafari, Version::new(17, 0)),
+            ],
+        )],
+    ),
+    ("mask", MASK),
+    ("mask-clip", MASK),
+    ("mask-composite", MASK),
+    ("mask-image", MASK),
+    ("mask-origin", MASK),
+    ("mask-position", MASK),
+    ("mask-repeat", MASK),
+    ("mask-size", MASK),
+    ("perspective", TRANSFORM),
+    ("perspective-origin", TRANSFORM),
+    (
+        "print-color-adjust",
+        &[(
+            Webkit,
+            &[
+                (Chrome, ALWAYS),
+                (Edge, ALWAYS),
+                (Safari, Version::new(15, 4)),
+                (IosSafari, Version::new(15, 4)),
+            ],
+        )],
+    ),
+    ("tab-size", &[(Moz, &[(Firefox, Version::new(91, 0))])]),
+    ("text-emphasis", TEXT_EMPHASIS),
+    ("text-emphasis-color", TEXT_EMPHASIS),
+    ("text-emphasis-position", TEXT_EMPHASIS),
+    ("text-emphasis-style", TEXT_EMPHASIS),
+    (
+        "text-size-adjust",
+        &[(Webkit, &[(Safari, ALWAYS), (IosSafari, ALWAYS)])],
+    ),
+    ("transform", TRANSFORM),
+    ("transform-origin", TRANSFORM),
+    ("transform-style", TRANSFORM),
+    ("transition", TRANSITION),
+    ("transition-delay", TRANSITION),
+    ("transition-duration", TRANSITION),
+    ("transition-property", TRANSITION),
+    ("transition-timing-function", TRANSITION),
+    (
+        "user-select",
+        &[
+            (
+                Webkit,
+                &[
+                    (Chrome, Version::new(54, 0)),
+                    (Safari, ALWAYS),
+                    (IosSafari, ALWAYS),
+                ],
+            ),
+            (Moz, &[(Firefox, Version::new(69, 0))]),
+        ],
+    ),
+];
+
+const FLEX: Prefixes = &[(
+    Webkit,
+    &[
+        (Chrome, Version::new(29, 0)),
+        (Safari, Version::new(9, 0)),
+        (IosSafari, Version::new(9, 0)),
+    ],
+)];
+
+const FIT_CONTENT: Prefixes = &[
+    (
+        Webkit,
+        &[
+            (Chrome, Version::new(46, 0)),
+            (Safari, Version::new(11, 0)),
+            (IosSafari, Version::new(11, 0)),
+        ],
+    ),
+    (Moz, &[(Firefox, Version::new(94, 0))]),
+];
+
+const SIZES: &[&str] = &[
+    "width",
+    "height",
+    "min-width",
+    "min-height",
+    "max-width",
+    "max-height",
+];
+
+/// Values that need a prefixed declaration, with the properties they apply to.
+pub(super) const VALUES: &[(&[&str], &str, Prefixes)] = &[
+    (&["display"], "flex", FLEX),
+    (&["display"], "inline-flex", FLEX),
+    (SIZES, "fit-content", FIT_CONTENT),
+    (
+        &["position"],
+        "sticky",
+        &[(
+            Webkit,
+            &[
+                (Safari, Version::new(13, 0)),
+                (IosSafari, Version::new(13, 0)),
+            ],
+        )],
+    ),
+];
+
+/// Pseudo classes and elements that need a prefixed selector.
+///
+/// Each pseudo is listed with its prefixed replacements.
+pub(super) const SELECTORS: &[(&str, &[(&str, Support)])] = &[
+    (
+        "::placeholder",
+        &[
+            (
+                "::-webkit-input-placeholder",
+                &[
+                    (Chrome, Version::new(57, 0)),
+                    (Safari, Version::new(10, 1)),
+                    (IosSafari, Version::new(10, 3)),
+                ],
+            ),
+            ("::-moz-placeholder", &[(Firefox, Version::new(51, 0))]),
+        ],
+    ),
+    (
+        "::selection",
+        &[("::-moz-selection", &[(Firefox, Version::new(62, 0))])],
+    ),
+    (
+        "::file-selector-button",
+        &[(
+            "::-webkit-file-upload-button",
+            &[
+                (Chrome, Version::new(89, 0)),
+                (Edge, Version::new(89, 0)),
+                (Safari, Version::new(14, 1)),
+                (IosSafari, Version::new(14, 5)),
+            ],
+        )],
+    ),
+    (
+        ":fullscreen",
+        &[
+            (
+                ":-webkit-full-screen",
+                &[
+                    (Chrome, Version::new(71, 0)),
+                    (Edge, Version::new(79, 0)),
+                    (Safari, Version::new(16, 4)),
+                    (IosSafari, Version::new(16, 4)),
+                ],
+            ),
+            (":-moz-full-screen", &[(Firefox, Version::new(64, 0))]),
+        ],
+    ),
+    (
+        ":any-link",
+        &[
+            (
+                ":-webkit-any-link",
+                &[
+                    (Chrome, Version::new(65, 0)),
+                    (Safari, Version::new(9, 0)),
+                    (IosSafari, Version::new(9, 0)),
+                ],
+            ),
+            (":-moz-any-link", &[(Firefox, Version::new(50, 0))]),
+        ],
+    ),
+    (
+        ":autofill",
+        &[(
+            ":-webkit-autofill",
+            &[
+                (Chrome, Version::new(110, 0)),
+                (Edge, Version::new(110, 0)),
+                (Safari, Version::new(15, 0)),
+                (IosSafari, Version::new(15, 0)),
+            ],
+        )],
+    ),
+];
+
+/// At-rules that need a prefixed copy.
+pub(super) const AT_RULES: &[(&str, Prefixes)] = &[("@keyframes", ANIMATION)];
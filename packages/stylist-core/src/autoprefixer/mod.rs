@@ -0,0 +1,560 @@
+//! Automatic vendor prefixing of stylesheets.
+//!
+//! This module adds vendor prefixed declarations, selectors and at-rules to a stylesheet for
+//! the browsers listed in [`Targets`].
+//!
+//! ```
+//! use stylist_core::ast::{Sheet, ToStyleStr};
+//! use stylist_core::autoprefixer::Targets;
+//!
+//! # #[cfg(feature = "parser")]
+//! # {
+//! let targets: Targets = "safari >= 12, chrome >= 90".parse().unwrap();
+//! let sheet: Sheet = "user-select: none;".parse().unwrap();
+//!
+//! assert_eq!(
+//!     sheet.autoprefix(&targets).to_style_str(Some("test")),
+//!     ".test {\n    -webkit-user-select: none;\n    user-select: none;\n}\n"
+//! );
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::ast::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+};
+use crate::bow::Bow;
+use crate::{Error, Result};
+
+mod data;
+
+use data::{AT_RULES, PROPERTIES, SELECTORS, VALUES};
+
+/// The browsers known to the autoprefixer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Browser {
+    Chrome,
+    Edge,
+    Firefox,
+    Safari,
+    IosSafari,
+}
+
+impl Browser {
+    fn from_name(name: &str) -> Option<Self> {
+        let browser = match name.to_ascii_lowercase().as_str() {
+            "chrome" => Self::Chrome,
+            "edge" => Self::Edge,
+            "firefox" | "ff" => Self::Firefox,
+            "safari" => Self::Safari,
+            "ios_saf" | "ios_safari" | "ios" => Self::IosSafari,
+            _ => return None,
+        };
+
+        Some(browser)
+    }
+}
+
+/// A browser version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl Version {
+    /// Creates a new version.
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::Targets {
+            reason: format!("invalid version `{}`", s),
+        };
+
+        let mut parts = s.trim().splitn(2, '.');
+        let major = parts
+            .next()
+            .and_then(|m| m.parse().ok())
+            .ok_or_else(invalid)?;
+        let minor = match parts.next() {
+            Some(m) => m.parse().map_err(|_| invalid())?,
+            None => 0,
+        };
+
+        Ok(Self::new(major, minor))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// A vendor prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Prefix {
+    Webkit,
+    Moz,
+}
+
+impl Prefix {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Webkit => "-webkit-",
+            Self::Moz => "-moz-",
+        }
+    }
+}
+
+/// The first unprefixed version for each browser that needed a prefix.
+type Support = &'static [(Browser, Version)];
+
+/// The vendor prefixes of a feature, each with the browsers that need it.
+type Prefixes = &'static [(Prefix, Support)];
+
+/// The browsers a stylesheet should support.
+///
+/// Targets can be parsed from a browserslist-like query, where each comma separated entry
+/// names a browser and its minimum version (e.g.: `safari >= 12, firefox >= 78`).
+///
+/// Supported browser names are `chrome`, `edge`, `firefox` (or `ff`), `safari` and
+/// `ios_saf`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Targets {
+    browsers: Vec<(Browser, Version)>,
+}
+
+impl Targets {
+    /// Creates an empty set of targets, which does not add any prefix.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a browser with its minimum supported version.
+    pub fn browser(mut self, browser: Browser, version: Version) -> Self {
+        match self.browsers.iter_mut().find(|(b, _)| *b == browser) {
+            Some((_, v)) => *v = (*v).min(version),
+            None => self.browsers.push((browser, version)),
+        }
+
+        self
+    }
+
+    /// Returns the minimum supported version of a browser, if it is targeted.
+    pub fn get(&self, browser: Browser) -> Option<Version> {
+        self.browsers
+            .iter()
+            .find(|(b, _)| *b == browser)
+            .map(|(_, v)| *v)
+    }
+
+    fn needs(&self, support: Support) -> bool {
+        support
+            .iter()
+            .any(|(browser, until)| self.get(*browser).map(|m| m < *until).unwrap_or(false))
+    }
+}
+
+impl FromStr for Targets {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut targets = Self::new();
+
+        for entry in s.split(',').map(|m| m.trim()).filter(|m| !m.is_empty()) {
+            let (name, version) = match entry.find(char::is_whitespace) {
+                Some(m) => (&entry[..m], entry[m..].trim_start()),
+                None => {
+                    return Err(Error::Targets {
+                        reason: format!("missing version for `{}`", entry),
+                    })
+                }
+            };
+
+            let browser = Browser::from_name(name).ok_or_else(|| Error::Targets {
+                reason: format!("unknown browser `{}`", name),
+            })?;
+            let version = version.strip_prefix(">=").unwrap_or(version).parse()?;
+
+            targets = targets.browser(browser, version);
+        }
+
+        Ok(targets)
+    }
+}
+
+impl Sheet {
+    /// Creates a new stylesheet with vendor prefixes added for `targets`.
+    ///
+    /// Prefixed declarations and selectors are inserted before their unprefixed counterparts,
+    /// so the standard syntax takes precedence where it is supported. Prefixed declarations
+    /// that are already present in the stylesheet are not duplicated.
+    pub fn autoprefix(&self, targets: &Targets) -> Self {
+        let mut contents = Vec::with_capacity(self.len());
+
+        for scope in self.iter() {
+            match scope {
+                ScopeContent::Block(ref m) => contents.extend(
+                    prefix_block(m, targets)
+                        .into_iter()
+                        .map(ScopeContent::Block),
+                ),
+                ScopeContent::Rule(ref m) => {
+                    contents.extend(prefix_rule(m, targets).into_iter().map(ScopeContent::Rule))
+                }
+            }
+        }
+
+        contents.into()
+    }
+}
+
+fn prefix_block(block: &Block, targets: &Targets) -> Vec<Block> {
+    let content: Cow<'static, [RuleBlockContent]> = prefix_contents(&block.content, targets).into();
+    let mut blocks = Vec::new();
+
+    for (pseudo, replacements) in SELECTORS {
+        for (replacement, support) in replacements.iter() {
+            if !targets.needs(support) {
+                continue;
+            }
+
+            let condition = block
+                .condition
+                .iter()
+                .filter_map(|m| prefix_selector(m, pseudo, replacement))
+                .collect::<Vec<Selector>>();
+
+            if !condition.is_empty() {
+                blocks.push(Block {
+                    condition: condition.into(),
+                    content: content.clone(),
+                });
+            }
+        }
+    }
+
+    blocks.push(Block {
+        condition: block.condition.clone(),
+        content,
+    });
+
+    blocks
+}
+
+fn prefix_rule(rule: &Rule, targets: &Targets) -> Vec<Rule> {
+    let content: Cow<'static, [RuleBlockContent]> = prefix_contents(&rule.content, targets).into();
+    let mut rules = Vec::new();
+
+    let first = match rule.condition.first() {
+        Some(m) => m.inner.as_ref(),
+        None => return vec![rule.clone()],
+    };
+
+    for (at_rule, prefixes) in AT_RULES {
+        let rest = match first.strip_prefix(at_rule) {
+            Some(m) if m.is_empty() || m.starts_with(char::is_whitespace) => m,
+            _ => continue,
+        };
+
+        for (prefix, support) in prefixes.iter() {
+            if !targets.needs(support) {
+                continue;
+            }
+
+            let mut condition = rule.condition.to_vec();
+            condition[0] = format!("@{}{}{}", prefix.as_str(), &at_rule[1..], rest).into();
+
+            rules.push(Rule {
+                condition: condition.into(),
+                content: content.clone(),
+            });
+        }
+    }
+
+    rules.push(Rule {
+        condition: rule.condition.clone(),
+        content,
+    });
+
+    rules
+}
+
+fn prefix_contents(contents: &[RuleBlockContent], targets: &Targets) -> Vec<RuleBlockContent> {
+    let mut prefixed = Vec::with_capacity(contents.len());
+
+    for content in contents.iter() {
+        match content {
+            RuleBlockContent::StyleAttr(ref m) => prefix_attr(m, contents, targets, &mut prefixed),
+            RuleBlockContent::Block(ref m) => prefixed.extend(
+                prefix_block(m, targets)
+                    .into_iter()
+                    .map(|m| RuleBlockContent::Block(Bow::Boxed(Box::new(m)))),
+            ),
+            RuleBlockContent::Rule(ref m) => prefixed.extend(
+                prefix_rule(m, targets)
+                    .into_iter()
+                    .map(|m| RuleBlockContent::Rule(Bow::Boxed(Box::new(m)))),
+            ),
+        }
+    }
+
+    prefixed
+}
+
+fn prefix_attr(
+    attr: &StyleAttribute,
+    siblings: &[RuleBlockContent],
+    targets: &Targets,
+    prefixed: &mut Vec<RuleBlockContent>,
+) {
+    let exists = |key: &str, value: Option<&str>| {
+        siblings.iter().any(|m| match m {
+            RuleBlockContent::StyleAttr(ref m) => {
+                m.key.eq_ignore_ascii_case(key)
+                    && value
+                        .map(|value| joined_value(m).eq_ignore_ascii_case(value))
+                        .unwrap_or(true)
+            }
+            _ => false,
+        })
+    };
+
+    let key = attr.key.to_ascii_lowercase();
+
+    if let Some((_, prefixes)) = PROPERTIES.iter().find(|(m, _)| *m == key) {
+        for (prefix, support) in prefixes.iter() {
+            let prefixed_key = format!("{}{}", prefix.as_str(), key);
+
+            if targets.needs(support) && !exists(&prefixed_key, None) {
+                prefixed.push(RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: prefixed_key.into(),
+                    value: attr.value.clone(),
+                }));
+            }
+        }
+    }
+
+    let value = joined_value(attr);
+
+    for (properties, keyword, prefixes) in VALUES {
+        if !properties.contains(&key.as_str()) || !value.eq_ignore_ascii_case(keyword) {
+            continue;
+        }
+
+        for (prefix, support) in prefixes.iter() {
+            let prefixed_value = format!("{}{}", prefix.as_str(), keyword);
+
+            if targets.needs(support) && !exists(&key, Some(&prefixed_value)) {
+                prefixed.push(RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: attr.key.clone(),
+                    value: vec![prefixed_value.into()].into(),
+                }));
+            }
+        }
+    }
+
+    prefixed.push(RuleBlockContent::StyleAttr(attr.clone()));
+}
+
+fn joined_value(attr: &StyleAttribute) -> String {
+    attr.value
+        .iter()
+        .map(|m| m.inner.as_ref())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Replaces all occurrences of `pseudo` in a selector, returns `None` if there is none.
+fn prefix_selector(selector: &Selector, pseudo: &str, replacement: &str) -> Option<Selector> {
+    let mut found = false;
+
+    let fragments = selector
+        .fragments
+        .iter()
+        .map(|m| match replace_pseudo(&m.inner, pseudo, replacement) {
+            Some(s) => {
+                found = true;
+                s.into()
+            }
+            None => m.clone(),
+        })
+        .collect::<Vec<StringFragment>>();
+
+    if found {
+        Some(fragments.into())
+    } else {
+        None
+    }
+}
+
+fn replace_pseudo(s: &str, pseudo: &str, replacement: &str) -> Option<String> {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+
+    let mut replaced = String::with_capacity(s.len());
+    let mut found = false;
+    let mut rest = s;
+
+    while let Some(pos) = rest.find(pseudo) {
+        let end = pos + pseudo.len();
+        // `:fullscreen` must not match `::fullscreen` and `::selection` must not match
+        // `::selection-foo`.
+        let is_match = !rest[end..].starts_with(is_ident_char)
+            && (pseudo.starts_with("::") || !rest[..pos].ends_with(':'));
+
+        replaced.push_str(&rest[..pos]);
+        if is_match {
+            replaced.push_str(replacement);
+            found = true;
+        } else {
+            replaced.push_str(pseudo);
+        }
+
+        rest = &rest[end..];
+    }
+
+    replaced.push_str(rest);
+
+    if found {
+        Some(replaced)
+    } else {
+        None
+    }
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[test]
+    fn test_data_sorted() {
+        assert!(PROPERTIES.windows(2).all(|m| m[0].0 < m[1].0));
+    }
+
+    #[test]
+    fn test_targets() {
+        let targets: Targets = "safari >= 12.1, ff 78, ios_saf >= 12, safari >= 11"
+            .parse()
+            .expect("Failed to parse targets.");
+
+        assert_eq!(targets.get(Browser::Safari), Some(Version::new(11, 0)));
+        assert_eq!(targets.get(Browser::Firefox), Some(Version::new(78, 0)));
+        assert_eq!(targets.get(Browser::IosSafari), Some(Version::new(12, 0)));
+        assert_eq!(targets.get(Browser::Chrome), None);
+
+        assert!("netscape >= 4".parse::<Targets>().is_err());
+        assert!("safari".parse::<Targets>().is_err());
+        assert!("safari >= latest".parse::<Targets>().is_err());
+    }
+
+    #[test]
+    fn test_autoprefix() {
+        let sheet: Sheet = r#"
+            input::placeholder, span {
+                transform: scale(2);
+                -webkit-transform: scale(2);
+            }
+
+            .sticky {
+                position: sticky;
+                user-select: none;
+            }
+
+            @keyframes spin {
+                from {
+                    transform: rotate(0deg);
+                }
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        let targets = Targets::new()
+            .browser(Browser::Safari, Version::new(8, 0))
+            .browser(Browser::Firefox, Version::new(90, 0));
+
+        let style_str = sheet.autoprefix(&targets).to_style_str(Some("test"));
+
+        // The prefixed stylesheet must remain parsable.
+        style_str
+            .parse::<Sheet>()
+            .expect("Failed to parse prefixed sheet.");
+
+        assert_eq!(
+            style_str,
+            r#".test input::-webkit-input-placeholder {
+    transform: scale(2);
+    -webkit-transform: scale(2);
+}
+.test input::placeholder, .test span {
+    transform: scale(2);
+    -webkit-transform: scale(2);
+}
+.test .sticky {
+    position: -webkit-sticky;
+    position: sticky;
+    -webkit-user-select: none;
+    user-select: none;
+}
+@-webkit-keyframes spin {
+    from {
+        -webkit-transform: rotate(0deg);
+        transform: rotate(0deg);
+    }
+}
+@keyframes spin {
+    from {
+        -webkit-transform: rotate(0deg);
+        transform: rotate(0deg);
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_autoprefix_modern() {
+        let sheet: Sheet = r#"
+            transform: scale(2);
+            input::placeholder {
+                color: gray;
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        let targets: Targets = "chrome >= 90, firefox >= 90, safari >= 15"
+            .parse()
+            .expect("Failed to parse targets.");
+
+        assert_eq!(sheet.autoprefix(&targets), sheet);
+    }
+
+    #[test]
+    fn test_replace_pseudo() {
+        assert_eq!(
+            replace_pseudo("a:fullscreen", ":fullscreen", ":-moz-full-screen"),
+            Some("a:-moz-full-screen".to_string())
+        );
+        assert_eq!(
+            replace_pseudo("a::fullscreen", ":fullscreen", ":-moz-full-screen"),
+            None
+        );
+        assert_eq!(
+            replace_pseudo(":placeholder-shown", "::placeholder", "::-moz-placeholder"),
+            None
+        );
+    }
+}
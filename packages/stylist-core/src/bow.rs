@@ -21,6 +21,20 @@ impl<T: ?Sized> Deref for Bow<'_, T> {
     }
 }
 
+impl<T: Clone> Bow<'_, T> {
+    /// Acquires a mutable reference to the owned value, cloning the data if it is borrowed.
+    pub fn to_mut(&mut self) -> &mut T {
+        if let Borrowed(b) = *self {
+            *self = Boxed(Box::new(b.clone()));
+        }
+
+        match self {
+            Borrowed(_) => unreachable!(),
+            Boxed(ref mut b) => b,
+        }
+    }
+}
+
 impl<T: ?Sized> AsRef<T> for Bow<'_, T> {
     fn as_ref(&self) -> &T {
         self
@@ -61,3 +75,24 @@ impl<T> From<T> for Bow<'_, T> {
         Bow::Boxed(t.into())
     }
 }
+
+#[cfg_attr(documenting, doc(cfg(feature = "serde")))]
+#[cfg(feature = "serde")]
+mod feat_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    impl<T: ?Sized + Serialize> Serialize for Bow<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            (**self).serialize(serializer)
+        }
+    }
+
+    // Deserialized values are always owned.
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Bow<'_, T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            T::deserialize(deserializer).map(Bow::from)
+        }
+    }
+}
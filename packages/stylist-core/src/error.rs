@@ -17,10 +17,68 @@ pub enum Error {
     /// This is usually raised when the style element failed to mount.
     #[error("Failed to Interact with Web API. Are you running in Browser?")]
     Web(Option<wasm_bindgen::JsValue>),
+
+    /// Failed to interpolate a stylesheet.
+    ///
+    /// This is raised when no value is provided for a placeholder.
+    #[error("Failed to Interpolate Stylesheet, missing argument: {}", .name)]
+    Interpolation { name: String },
+
+    /// Failed to parse a property value into a typed [`Value`](crate::ast::Value).
+    #[error("Failed to Parse Value, due to:\n{}", .reason)]
+    Value { reason: String },
+
+    /// A style attribute failed validation.
+    ///
+    /// This is raised when a property is unknown or a value does not match its grammar.
+    #[cfg_attr(documenting, doc(cfg(feature = "validation")))]
+    #[cfg(feature = "validation")]
+    #[error("Failed to Validate CSS, due to:\n{}", .reason)]
+    Validation { reason: String },
+
+    /// Failed to parse browser targets for the autoprefixer.
+    #[cfg_attr(documenting, doc(cfg(feature = "autoprefixer")))]
+    #[cfg(feature = "autoprefixer")]
+    #[error("Failed to Parse Browser Targets, due to:\n{}", .reason)]
+    Targets { reason: String },
+}
+
+impl Error {
+    /// Returns the byte offset in `input` where this error occurred, if known.
+    ///
+    /// `input` must be the string that was parsed.
+    #[cfg_attr(documenting, doc(cfg(feature = "parser")))]
+    #[cfg(feature = "parser")]
+    pub fn parse_offset(&self, input: &str) -> Option<usize> {
+        // The parser reports the remaining input where the innermost error occurred.
+        match self {
+            Self::Parse {
+                source: Some(ref m),
+                ..
+            } => m.errors.first().map(|(rest, _)| input.len() - rest.len()),
+            _ => None,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Returns the 1-based line and column of the byte `offset` in `s`.
+///
+/// Columns are counted in characters.
+pub fn line_column(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map(|m| m.chars().count())
+        .unwrap_or(0)
+        + 1;
+
+    (line, column)
+}
+
 pub trait ResultDisplay<T> {
     /// Returns the contained Ok value, consuming the self value, panic when `Result` is `Err`.
     fn unwrap_display(self) -> T;
@@ -43,3 +101,27 @@ impl<T> ResultDisplay<T> for Result<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_column() {
+        assert_eq!(line_column("a\nbc", 0), (1, 1));
+        assert_eq!(line_column("a\nbc", 3), (2, 2));
+        assert_eq!(line_column("é\nb", 2), (1, 2));
+    }
+
+    #[cfg(feature = "parser")]
+    #[test]
+    fn test_parse_offset() {
+        let input = "color: red;\n.a {\n    color: blue;\n";
+        let e = input.parse::<crate::ast::Sheet>().unwrap_err();
+
+        assert_eq!(
+            e.parse_offset(input).map(|m| line_column(input, m)),
+            Some((2, 1))
+        );
+    }
+}
@@ -7,7 +7,7 @@
 #![cfg_attr(any(releasing, not(debug_assertions)), deny(dead_code, unused_imports))]
 
 mod error;
-pub use error::{Error, Result, ResultDisplay};
+pub use error::{line_column, Error, Result, ResultDisplay};
 pub mod ast;
 pub mod bow;
 
@@ -15,6 +15,18 @@ pub mod bow;
 #[cfg(feature = "parser")]
 mod parser;
 
+#[cfg_attr(documenting, doc(cfg(feature = "autoprefixer")))]
+#[cfg(feature = "autoprefixer")]
+pub mod autoprefixer;
+
+#[cfg_attr(documenting, doc(cfg(feature = "rtl")))]
+#[cfg(feature = "rtl")]
+pub mod rtl;
+
+#[cfg_attr(documenting, doc(cfg(feature = "validation")))]
+#[cfg(feature = "validation")]
+pub mod validation;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,7 +4,7 @@ use std::fmt;
 use nom::branch::{alt, Alt};
 use nom::bytes::complete::{is_not, tag, take_while1};
 use nom::character::complete::{alpha1, alphanumeric1, anychar, char, none_of};
-use nom::combinator::{fail, map, not, opt, recognize};
+use nom::combinator::{map, not, opt, recognize, verify};
 use nom::error::{convert_error, ErrorKind, ParseError, VerboseError};
 use nom::multi::{many0, many1, separated_list1};
 use nom::sequence::{delimited, pair, preceded, separated_pair, terminated};
@@ -124,6 +124,19 @@ impl Parser {
         )(i)
     }
 
+    /// Parse a directive comment, such as `/* rtl:ignore */`.
+    ///
+    /// Directives in a value are kept so they can be read by transforms. Without feature `rtl`,
+    /// directives are parsed like other comments.
+    fn directive(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
+        traced_context(
+            "Directive",
+            recognize(verify(Self::cmt, |m: &str| {
+                cfg!(feature = "rtl") && m.trim_start().starts_with("rtl:")
+            })),
+        )(i)
+    }
+
     /// Parse whitespace
     fn sp(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
         let chars = " \t\r\n";
@@ -167,6 +180,7 @@ impl Parser {
                     is_not("${;}/\""),
                     recognize(Self::interpolation),
                     Self::string,
+                    Self::directive,
                     recognize(preceded(tag("/"), none_of("${;}/\"*"))),
                 )))),
                 |m: &str| StringFragment {
@@ -233,6 +247,8 @@ impl Parser {
 
     /// Parse a string interpolation.
     ///
+    /// The name is either an identifier or a path (`${Theme::primary}`).
+    ///
     // TODO: Handle escaping.
     fn interpolation(i: &str) -> IResult<&str, &str, VerboseError<&str>> {
         traced_context(
@@ -241,7 +257,7 @@ impl Parser {
                 tag("${"),
                 Self::trimmed(recognize(preceded(
                     alpha1,
-                    many0(alt((alphanumeric1, tag("_")))),
+                    many0(alt((alphanumeric1, tag("_"), tag("::")))),
                 ))),
                 tag("}"),
             ))),
@@ -259,6 +275,8 @@ impl Parser {
                     recognize(preceded(none_of("$,}@{\""), opt(is_not("$,\"{")))),
                     Self::string,
                     recognize(Self::interpolation),
+                    // A `$` that does not start an interpolation, e.g.: `[href$=".pdf"]`.
+                    recognize(terminated(char('$'), not(char('{')))),
                 )))),
                 |p: &str| vec![p.trim().to_owned().into()].into(),
             ))),
@@ -434,7 +452,7 @@ impl Parser {
             Self::trimmed(map(
                 separated_pair(
                     // Collect at Rules.
-                    |i| Self::at_rule_condition(i, (tag("@keyframes"), fail)),
+                    |i| Self::at_rule_condition(i, (tag("@keyframes"), tag("@-webkit-keyframes"))),
                     tag("{"),
                     // Collect contents with-in rules.
                     terminated(
@@ -952,6 +970,10 @@ mod tests {
             Parser::selector("& input").map(|m| m.1),
             Ok(vec!["& input".into()].into())
         );
+        assert_eq!(
+            Parser::selector(r#"a[href$=".pdf"]"#).map(|m| m.1),
+            Ok(vec![r#"a[href$=".pdf"]"#.into()].into())
+        );
     }
 
     #[test]
@@ -1000,6 +1022,24 @@ mod tests {
         assert_eq!(parsed, expected);
     }
 
+    #[test]
+    fn test_path_interpolation() {
+        init();
+        let test_str = r#"color: ${Theme::FONT_COLOR};"#;
+        let parsed = Parser::parse(test_str).expect("Failed to Parse Style");
+
+        let expected = Sheet::from(vec![ScopeContent::Block(Block {
+            condition: Cow::Borrowed(&[]),
+            content: vec![StyleAttribute {
+                key: "color".into(),
+                value: vec!["${Theme::FONT_COLOR}".into()].into(),
+            }
+            .into()]
+            .into(),
+        })]);
+        assert_eq!(parsed, expected);
+    }
+
     #[test]
     fn test_empty_block() {
         init();
@@ -1454,4 +1494,35 @@ mod tests {
 
         assert_eq!(parsed, expected);
     }
+
+    #[test]
+    fn test_directive() {
+        let test_str = r#"
+            margin-left: 4px /* rtl:ignore */;
+            padding-left: 4px /* not a directive */;
+        "#;
+
+        let parsed = Parser::parse(test_str).expect("Failed to Parse Style");
+
+        let expected = Sheet::from(vec![ScopeContent::Block(Block {
+            condition: vec![].into(),
+            content: vec![
+                RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: "margin-left".into(),
+                    value: if cfg!(feature = "rtl") {
+                        vec!["4px /* rtl:ignore */".into()].into()
+                    } else {
+                        vec!["4px".into()].into()
+                    },
+                }),
+                RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: "padding-left".into(),
+                    value: vec!["4px".into()].into(),
+                }),
+            ]
+            .into(),
+        })]);
+
+        assert_eq!(parsed, expected);
+    }
 }
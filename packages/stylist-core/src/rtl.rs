@@ -0,0 +1,792 @@
+//! Flipping of stylesheets for right-to-left languages.
+//!
+//! This module mirrors directional properties and values of a stylesheet, in the spirit of
+//! [rtlcss](https://rtlcss.com/):
+//!
+//! - Property names: `margin-left` becomes `margin-right`, `border-top-left-radius` becomes
+//!   `border-top-right-radius`.
+//! - Keywords: `left` becomes `right` and `ltr` becomes `rtl` in properties that take direction
+//!   keywords (e.g.: `float`, `text-align`) or property names (e.g.: `transition-property`).
+//!   Identifiers in other properties, like `animation-name: slide-left`, are left untouched.
+//! - Shorthands: `margin: 1px 2px 3px 4px` becomes `margin: 1px 4px 3px 2px`, the same applies
+//!   to `padding`, `border-width`, `border-radius` and friends.
+//! - Transforms: `translateX(10px)` becomes `translateX(-10px)`.
+//! - Shadows: the horizontal offset of `box-shadow` and `text-shadow` is negated.
+//! - Cursors: `e-resize` becomes `w-resize`.
+//!
+//! A declaration is left untouched if its value ends with an `/* rtl:ignore */` directive:
+//!
+//! ```css
+//! margin-left: 4px /* rtl:ignore */;
+//! ```
+//!
+//! Directives are only available in the string literal syntax, as comments are not visible to
+//! procedural macros.
+
+use crate::ast::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+    VisitorMut,
+};
+use crate::bow::Bow;
+
+const IGNORE_DIRECTIVE: &str = "rtl:ignore";
+
+/// Properties with a `top right bottom left` shorthand.
+const BOX_SHORTHANDS: &[&str] = &[
+    "border-color",
+    "border-style",
+    "border-width",
+    "inset",
+    "margin",
+    "padding",
+    "scroll-margin",
+    "scroll-padding",
+];
+
+/// Properties whose values contain direction keywords (e.g.: `float: left`) or names of
+/// directional properties (e.g.: `transition-property: margin-left`), which are flipped.
+///
+/// Identifiers in other properties are left untouched, as they may refer to names defined
+/// elsewhere, like `animation-name: slide-left` or `grid-area: left-col`.
+const KEYWORD_PROPERTIES: &[&str] = &[
+    "background",
+    "background-position",
+    "background-position-x",
+    "clear",
+    "direction",
+    "float",
+    "justify-content",
+    "justify-items",
+    "justify-self",
+    "mask-position",
+    "object-position",
+    "perspective-origin",
+    "text-align",
+    "text-align-last",
+    "transform-origin",
+    "transition",
+    "transition-property",
+    "will-change",
+];
+
+/// Functions in `transform` that are mirrored, with the indices of arguments to negate.
+const TRANSFORM_FUNCTIONS: &[(&str, &[usize])] = &[
+    ("rotate", &[0]),
+    ("rotatez", &[0]),
+    ("skew", &[0, 1]),
+    ("skewx", &[0]),
+    ("translate", &[0]),
+    ("translate3d", &[0]),
+    ("translatex", &[0]),
+];
+
+impl Sheet {
+    /// Creates a new stylesheet with all directional properties and values flipped.
+    ///
+    /// See the [module documentation](crate::rtl) for what is flipped.
+    pub fn to_rtl(&self) -> Self {
+        struct Flip;
+
+        impl VisitorMut for Flip {
+            fn visit_style_attr_mut(&mut self, attr: &mut StyleAttribute) {
+                *attr = flip_attr(attr);
+            }
+        }
+
+        let mut sheet = self.clone();
+        Flip.visit_sheet_mut(&mut sheet);
+
+        sheet
+    }
+
+    /// Creates a new stylesheet which contains this stylesheet, followed by overrides that flip
+    /// it for elements in a `[dir=rtl]` container.
+    ///
+    /// Overrides contain only the flipped declarations. Declarations whose property changes are
+    /// reset with `unset` first. Keyframes are not overridden, as they cannot be scoped.
+    ///
+    /// `is_global` should be `true` if the stylesheet is not scoped to a class name, in which
+    /// case `[dir=rtl]` is expected on the root element.
+    pub fn with_rtl_overrides(&self, is_global: bool) -> Self {
+        let overrides = self.iter().filter_map(|m| match m {
+            ScopeContent::Block(ref m) => rtl_block(m, is_global).map(ScopeContent::Block),
+            ScopeContent::Rule(ref m) => rtl_rule(m, is_global).map(ScopeContent::Rule),
+        });
+
+        self.iter()
+            .cloned()
+            .chain(overrides)
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+fn rtl_block(block: &Block, is_global: bool) -> Option<Block> {
+    let content = rtl_contents(&block.content, is_global);
+
+    if content.is_empty() {
+        return None;
+    }
+
+    let condition = if block.condition.is_empty() {
+        let selector = if is_global {
+            "&[dir=rtl]"
+        } else {
+            "[dir=rtl] &"
+        };
+        vec![vec![selector.into()].into()]
+    } else {
+        block
+            .condition
+            .iter()
+            .map(|m| scope_selector(m, is_global))
+            .collect()
+    };
+
+    Some(Block {
+        condition: condition.into(),
+        content: content.into(),
+    })
+}
+
+fn rtl_rule(rule: &Rule, is_global: bool) -> Option<Rule> {
+    let is_keyframes = rule
+        .condition
+        .first()
+        .map(|m| m.inner.starts_with("@keyframes") || m.inner.starts_with("@-webkit-keyframes"))
+        .unwrap_or(false);
+
+    if is_keyframes {
+        return None;
+    }
+
+    let content = rtl_contents(&rule.content, is_global);
+
+    if content.is_empty() {
+        return None;
+    }
+
+    Some(Rule {
+        condition: rule.condition.clone(),
+        content: content.into(),
+    })
+}
+
+fn rtl_contents(contents: &[RuleBlockContent], is_global: bool) -> Vec<RuleBlockContent> {
+    let attrs = contents
+        .iter()
+        .filter_map(|m| match m {
+            RuleBlockContent::StyleAttr(ref m) => Some((m, flip_attr(m))),
+            _ => None,
+        })
+        .filter(|(attr, flipped)| *attr != flipped)
+        .collect::<Vec<_>>();
+
+    let is_flipped_key = |key: &str| {
+        contents.iter().any(|m| match m {
+            RuleBlockContent::StyleAttr(ref m) => flip_attr(m).key.eq_ignore_ascii_case(key),
+            _ => false,
+        })
+    };
+
+    let resets = attrs
+        .iter()
+        .filter(|(attr, _)| !is_flipped_key(&attr.key))
+        .map(|(attr, _)| StyleAttribute {
+            key: attr.key.clone(),
+            value: vec!["unset".into()].into(),
+        });
+
+    let mut overrides = resets
+        .chain(attrs.iter().map(|(_, flipped)| flipped.clone()))
+        .map(RuleBlockContent::StyleAttr)
+        .collect::<Vec<_>>();
+
+    for content in contents.iter() {
+        match content {
+            RuleBlockContent::StyleAttr(_) => {}
+            RuleBlockContent::Block(ref m) => {
+                if let Some(m) = rtl_block(m, is_global) {
+                    overrides.push(RuleBlockContent::Block(Bow::Boxed(Box::new(m))));
+                }
+            }
+            RuleBlockContent::Rule(ref m) => {
+                if let Some(m) = rtl_rule(m, is_global) {
+                    overrides.push(RuleBlockContent::Rule(Bow::Boxed(Box::new(m))));
+                }
+            }
+        }
+    }
+
+    overrides
+}
+
+fn scope_selector(selector: &Selector, is_global: bool) -> Selector {
+    let joined = selector
+        .fragments
+        .iter()
+        .map(|m| m.inner.as_ref())
+        .collect::<String>();
+
+    let scoped = if is_global {
+        if joined.contains('&') || joined.contains(":root") {
+            joined
+                .replace('&', "&[dir=rtl]")
+                .replace(":root", ":root[dir=rtl]")
+        } else {
+            format!("[dir=rtl] {}", joined)
+        }
+    } else if joined.contains('&') || joined.contains(":root") {
+        format!("[dir=rtl] {}", joined)
+    } else if joined.starts_with(':') {
+        format!("[dir=rtl] &{}", joined)
+    } else {
+        format!("[dir=rtl] & {}", joined)
+    };
+
+    vec![scoped.into()].into()
+}
+
+/// Flips a style attribute, returns a copy if it does not contain anything directional.
+pub fn flip_attr(attr: &StyleAttribute) -> StyleAttribute {
+    let value = attr
+        .value
+        .iter()
+        .map(|m| m.inner.as_ref())
+        .collect::<String>();
+
+    if attr.key.starts_with("--") || has_ignore_directive(&value) {
+        return attr.clone();
+    }
+
+    let key = flip_key(&attr.key);
+    let flipped = flip_value(&attr.key.to_ascii_lowercase(), &value);
+
+    if key == attr.key && flipped == value {
+        return attr.clone();
+    }
+
+    StyleAttribute {
+        key: key.into(),
+        value: vec![StringFragment::from(flipped)].into(),
+    }
+}
+
+fn has_ignore_directive(value: &str) -> bool {
+    let mut rest = value;
+
+    while let Some(start) = rest.find("/*") {
+        let end = match rest[start..].find("*/") {
+            Some(m) => start + m,
+            None => return false,
+        };
+
+        if rest[start + 2..end].trim() == IGNORE_DIRECTIVE {
+            return true;
+        }
+
+        rest = &rest[end + 2..];
+    }
+
+    false
+}
+
+fn flip_key(key: &str) -> String {
+    key.split('-')
+        .map(|m| swap_keyword(m).unwrap_or(m))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn swap_keyword(s: &str) -> Option<&'static str> {
+    let swapped = match s.to_ascii_lowercase().as_str() {
+        "left" => "right",
+        "right" => "left",
+        "ltr" => "rtl",
+        "rtl" => "ltr",
+        _ => return None,
+    };
+
+    Some(swapped)
+}
+
+fn flip_value(key: &str, value: &str) -> String {
+    // Vendor prefixes do not change the grammar.
+    let name = ["-webkit-", "-moz-", "-ms-", "-o-"]
+        .iter()
+        .find_map(|m| key.strip_prefix(m))
+        .unwrap_or(key);
+
+    let value = match name {
+        m if BOX_SHORTHANDS.contains(&m) => flip_box(value),
+        "border-radius" => flip_radius(value),
+        "box-shadow" | "text-shadow" => flip_shadow(value),
+        "transform" => flip_transform(value),
+        "translate" => flip_translate(value),
+        "background-position" | "background-position-x" => flip_position(value),
+        "cursor" => map_idents(value, |m| {
+            let direction = m.strip_suffix("-resize")?;
+            let swapped = match direction {
+                "e" => "w",
+                "w" => "e",
+                "ne" => "nw",
+                "nw" => "ne",
+                "se" => "sw",
+                "sw" => "se",
+                _ => return None,
+            };
+
+            Some(format!("{}-resize", swapped))
+        }),
+        _ => value.to_string(),
+    };
+
+    if !KEYWORD_PROPERTIES.contains(&name) {
+        return value;
+    }
+
+    map_idents(&value, |m| {
+        if !m.split('-').any(|m| swap_keyword(m).is_some()) {
+            return None;
+        }
+
+        Some(flip_key(m))
+    })
+}
+
+fn flip_box(value: &str) -> String {
+    let parts = split_top_level(value, ' ');
+
+    match parts.as_slice() {
+        [top, right, bottom, left] => [*top, *left, *bottom, *right].join(" "),
+        _ => value.to_string(),
+    }
+}
+
+fn flip_radius(value: &str) -> String {
+    split_top_level(value, '/')
+        .into_iter()
+        .map(|m| {
+            let parts = split_top_level(m, ' ');
+
+            match parts.as_slice() {
+                [a, b] => [*b, *a].join(" "),
+                [a, b, c] => [*b, *a, *b, *c].join(" "),
+                [a, b, c, d] => [*b, *a, *d, *c].join(" "),
+                _ => m.trim().to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+fn flip_shadow(value: &str) -> String {
+    split_top_level(value, ',')
+        .into_iter()
+        .map(|m| {
+            let mut parts = split_top_level(m, ' ')
+                .into_iter()
+                .map(|m| m.to_string())
+                .collect::<Vec<_>>();
+
+            if let Some(offset) = parts.iter_mut().find(|m| is_length(m)) {
+                *offset = negate(offset);
+            }
+
+            parts.join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn flip_transform(value: &str) -> String {
+    let mut flipped = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('(') {
+        let name_start = rest[..start]
+            .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .map(|m| m + 1)
+            .unwrap_or(0);
+        let name = rest[name_start..start].to_ascii_lowercase();
+        let end = match find_closing(&rest[start..]) {
+            Some(m) => start + m,
+            None => break,
+        };
+
+        flipped.push_str(&rest[..=start]);
+
+        let args = &rest[start + 1..end];
+        match TRANSFORM_FUNCTIONS.iter().find(|(m, _)| *m == name) {
+            Some((_, indices)) => {
+                let args = split_top_level(args, ',')
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, m)| {
+                        if indices.contains(&i) {
+                            negate(m)
+                        } else {
+                            m.trim().to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                flipped.push_str(&args.join(", "));
+            }
+            None => flipped.push_str(args),
+        }
+
+        flipped.push(')');
+        rest = &rest[end + 1..];
+    }
+
+    flipped.push_str(rest);
+    flipped
+}
+
+fn flip_translate(value: &str) -> String {
+    let mut parts = split_top_level(value, ' ')
+        .into_iter()
+        .map(|m| m.to_string())
+        .collect::<Vec<_>>();
+
+    if let Some(m) = parts.first_mut() {
+        *m = negate(m);
+    }
+
+    parts.join(" ")
+}
+
+fn flip_position(value: &str) -> String {
+    let mut parts = split_top_level(value, ' ')
+        .into_iter()
+        .map(|m| m.to_string())
+        .collect::<Vec<_>>();
+
+    // Percentages are relative to the left edge.
+    if parts.len() <= 2 {
+        if let Some(m) = parts.first_mut() {
+            if let Some(percent) = m.strip_suffix('%').and_then(|m| m.parse::<f64>().ok()) {
+                *m = format!("{}%", 100.0 - percent);
+            }
+        }
+    }
+
+    parts.join(" ")
+}
+
+fn is_length(s: &str) -> bool {
+    let is_number = s.starts_with(|c: char| c.is_ascii_digit() || c == '.' || c == '-' || c == '+')
+        && !s.starts_with("--");
+
+    is_number || s.to_ascii_lowercase().starts_with("calc(")
+}
+
+fn negate(s: &str) -> String {
+    let s = s.trim();
+
+    if s.contains('(') {
+        return format!("calc(-1 * {})", s);
+    }
+
+    if let Some(m) = s.strip_prefix('-') {
+        return m.to_string();
+    }
+
+    let s = s.strip_prefix('+').unwrap_or(s);
+
+    if s.trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '%')
+        .parse::<f64>()
+        .map(|m| m == 0.0)
+        .unwrap_or(false)
+    {
+        return s.to_string();
+    }
+
+    format!("-{}", s)
+}
+
+/// Returns the index of the `)` that closes the `(` at the start of `s`.
+fn find_closing(s: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Splits `s` at `sep` (or any whitespace if `sep` is a space), outside of parentheses and
+/// strings.
+fn split_top_level(s: &str, sep: char) -> Vec<&str> {
+    let is_sep = |c: char| {
+        if sep == ' ' {
+            c.is_whitespace()
+        } else {
+            c == sep
+        }
+    };
+
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, c) if depth == 0 && is_sep(c) => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+        .into_iter()
+        .map(|m| m.trim())
+        .filter(|m| !m.is_empty())
+        .collect()
+}
+
+/// Replaces identifiers in `s` with the result of `f`, skipping strings, urls and comments.
+fn map_idents<F>(s: &str, f: F) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
+    // Like the parser, non-ascii characters are part of identifiers.
+    let is_ident_char =
+        |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii();
+
+    let mut mapped = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        let skip_until = match c {
+            '"' | '\'' => rest[1..].find(c).map(|m| m + 2),
+            '/' if rest.starts_with("/*") => rest.find("*/").map(|m| m + 2),
+            _ if rest
+                .get(..4)
+                .map(|m| m.eq_ignore_ascii_case("url("))
+                .unwrap_or(false) =>
+            {
+                rest.find(')').map(|m| m + 1)
+            }
+            _ => None,
+        };
+
+        if let Some(end) = skip_until {
+            mapped.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        if is_ident_char(c) {
+            let end = rest.find(|c| !is_ident_char(c)).unwrap_or(rest.len());
+            let ident = &rest[..end];
+
+            match f(ident) {
+                Some(m) => mapped.push_str(&m),
+                None => mapped.push_str(ident),
+            }
+            rest = &rest[end..];
+            continue;
+        }
+
+        mapped.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    mapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flip(key: &'static str, value: &'static str) -> (String, String) {
+        let flipped = flip_attr(&StyleAttribute {
+            key: key.into(),
+            value: vec![value.into()].into(),
+        });
+
+        let value = flipped
+            .value
+            .iter()
+            .map(|m| m.inner.as_ref())
+            .collect::<String>();
+
+        (flipped.key.to_string(), value)
+    }
+
+    #[test]
+    fn test_flip_attr() {
+        let cases = [
+            (("margin-left", "4px"), ("margin-right", "4px")),
+            (
+                ("border-top-left-radius", "2px"),
+                ("border-top-right-radius", "2px"),
+            ),
+            (("float", "left"), ("float", "right")),
+            (("direction", "ltr"), ("direction", "rtl")),
+            (
+                ("padding", "1px 2px 3px 4px"),
+                ("padding", "1px 4px 3px 2px"),
+            ),
+            (("padding", "1px 2px"), ("padding", "1px 2px")),
+            (
+                ("border-radius", "1px 2px 3px"),
+                ("border-radius", "2px 1px 2px 3px"),
+            ),
+            (
+                ("border-radius", "1px 2px / 3px 4px"),
+                ("border-radius", "2px 1px / 4px 3px"),
+            ),
+            (
+                ("transform", "translateX(10px) rotate(-45deg) scale(2)"),
+                ("transform", "translateX(-10px) rotate(45deg) scale(2)"),
+            ),
+            (
+                ("transform", "translate(var(--x), 4px)"),
+                ("transform", "translate(calc(-1 * var(--x)), 4px)"),
+            ),
+            (
+                ("box-shadow", "inset 2px 4px rgba(0, 0, 0, 0.5), 0 1px red"),
+                ("box-shadow", "inset -2px 4px rgba(0, 0, 0, 0.5), 0 1px red"),
+            ),
+            (("cursor", "ne-resize"), ("cursor", "nw-resize")),
+            (
+                ("background-position", "20% 50%"),
+                ("background-position", "80% 50%"),
+            ),
+            (
+                ("transition", "margin-left 1s, left 2s"),
+                ("transition", "margin-right 1s, right 2s"),
+            ),
+            (
+                ("background", "url(left.png) no-repeat left"),
+                ("background", "url(left.png) no-repeat right"),
+            ),
+            (("content", "\"left\""), ("content", "\"left\"")),
+            (("--align", "left"), ("--align", "left")),
+            (
+                ("font-family", "Café, sans-serif"),
+                ("font-family", "Café, sans-serif"),
+            ),
+            (("float", "lefté"), ("float", "lefté")),
+            (("text-align", "left"), ("text-align", "right")),
+            (("clear", "right"), ("clear", "left")),
+            (
+                ("transition-property", "padding-left"),
+                ("transition-property", "padding-right"),
+            ),
+            (
+                ("animation-name", "slide-left"),
+                ("animation-name", "slide-left"),
+            ),
+            (
+                ("animation", "slide-left 1s"),
+                ("animation", "slide-left 1s"),
+            ),
+            (("grid-area", "left-col"), ("grid-area", "left-col")),
+            (
+                ("grid-template-areas", "\"left-col main\""),
+                ("grid-template-areas", "\"left-col main\""),
+            ),
+            (
+                ("margin-left", "4px /* rtl:ignore */"),
+                ("margin-left", "4px /* rtl:ignore */"),
+            ),
+        ];
+
+        for ((key, value), (expected_key, expected_value)) in cases.iter() {
+            assert_eq!(
+                flip(key, value),
+                (expected_key.to_string(), expected_value.to_string()),
+                "failed to flip `{}: {}`",
+                key,
+                value
+            );
+        }
+    }
+
+    #[cfg(feature = "parser")]
+    #[test]
+    fn test_rtl_overrides() {
+        use crate::ast::ToStyleStr;
+
+        let sheet: Sheet = r#"
+            margin-left: 4px;
+            color: red;
+
+            .icon {
+                float: left;
+                margin-right: 2px;
+                margin-left: 8px;
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        assert_eq!(
+            sheet.with_rtl_overrides(false).to_style_str(Some("test")),
+            r#".test {
+    margin-left: 4px;
+    color: red;
+}
+.test .icon {
+    float: left;
+    margin-right: 2px;
+    margin-left: 8px;
+}
+[dir=rtl] .test {
+    margin-left: unset;
+    margin-right: 4px;
+}
+[dir=rtl] .test .icon {
+    float: right;
+    margin-left: 2px;
+    margin-right: 8px;
+}
+"#
+        );
+
+        assert_eq!(
+            sheet.with_rtl_overrides(true).to_style_str(None),
+            r#":root {
+    margin-left: 4px;
+    color: red;
+}
+.icon {
+    float: left;
+    margin-right: 2px;
+    margin-left: 8px;
+}
+:root[dir=rtl] {
+    margin-left: unset;
+    margin-right: 4px;
+}
+[dir=rtl] .icon {
+    float: right;
+    margin-left: 2px;
+    margin-right: 8px;
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! Validation of style attributes against a bundled database of CSS properties.
+//!
+//! This module checks that property names are known and that values of properties with a keyword
+//! based grammar (e.g.: `display`) use a valid keyword.
+//!
+//! The following are always accepted:
+//! - Custom properties (`--my-var`).
+//! - Vendor prefixed properties (`-webkit-appearance`) and keywords (`-webkit-box`).
+//! - Properties and values containing an uninterpolated `${}` placeholder.
+//! - Global keywords (`inherit`, `initial`, `unset`, `revert`).
+//!
+//! # Allowing Unknown Properties
+//!
+//! Properties missing from the database can be allowed with the `STYLIST_ALLOWED_PROPERTIES`
+//! environment variable, a comma separated list of property names, or `*` to allow all unknown
+//! properties. It is read when stylist is compiled, so it is usually set in the `[env]` section
+//! of `.cargo/config.toml`:
+//!
+//! ```toml
+//! [env]
+//! STYLIST_ALLOWED_PROPERTIES = "my-new-property, another-property"
+//! ```
+//!
+//! Values of allowed properties are not checked.
+
+use crate::ast::{RuleBlockContent, ScopeContent, Sheet, StyleAttribute, Value};
+use crate::{Error, Result};
+
+mod properties;
+
+use properties::{KEYWORDS, PROPERTIES};
+
+const GLOBAL_KEYWORDS: &[&str] = &["inherit", "initial", "unset", "revert", "revert-layer"];
+const VENDOR_PREFIXES: &[&str] = &["-webkit-", "-moz-", "-ms-", "-o-"];
+
+/// Returns `true` if `name` is a known CSS property.
+pub fn is_known_property(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+
+    name.starts_with("--")
+        || VENDOR_PREFIXES.iter().any(|m| name.starts_with(m))
+        || PROPERTIES.binary_search(&name.as_str()).is_ok()
+}
+
+/// Returns `true` if `name` is allowed by the `STYLIST_ALLOWED_PROPERTIES` environment variable.
+///
+/// See the [module documentation](self#allowing-unknown-properties).
+pub fn is_allowed_property(name: &str) -> bool {
+    is_allowed_by(
+        option_env!("STYLIST_ALLOWED_PROPERTIES").unwrap_or(""),
+        name,
+    )
+}
+
+fn is_allowed_by(allowed: &str, name: &str) -> bool {
+    allowed
+        .split(',')
+        .map(str::trim)
+        .any(|m| m == "*" || m.eq_ignore_ascii_case(name))
+}
+
+/// Returns the known property that is most similar to `name`, if any is similar enough.
+pub fn suggest_property(name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_lowercase();
+
+    PROPERTIES
+        .iter()
+        .map(|m| (edit_distance(&name, m), *m))
+        .filter(|(dist, _)| *dist <= 2)
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, m)| m)
+}
+
+/// Checks that `name` is a known CSS property.
+pub fn validate_property(name: &str) -> Result<()> {
+    if name.contains("${") || is_known_property(name) || is_allowed_property(name) {
+        return Ok(());
+    }
+
+    let reason = match suggest_property(name) {
+        Some(m) => format!("unknown property `{}`, did you mean `{}`?", name, m),
+        None => format!("unknown property `{}`", name),
+    };
+
+    Err(Error::Validation { reason })
+}
+
+/// Checks that `value` is valid for property `name`.
+///
+/// Only properties with a keyword based grammar are checked at the moment.
+pub fn validate_value(name: &str, value: &Value) -> Result<()> {
+    let keyword = match value {
+        Value::Keyword(m) => m,
+        _ => return Ok(()),
+    };
+
+    let name = name.to_ascii_lowercase();
+    let allowed = match KEYWORDS.binary_search_by_key(&name.as_str(), |(m, _)| m) {
+        Ok(m) => KEYWORDS[m].1,
+        Err(_) => return Ok(()),
+    };
+
+    let lowercase_keyword = keyword.to_ascii_lowercase();
+    if VENDOR_PREFIXES
+        .iter()
+        .any(|m| lowercase_keyword.starts_with(m))
+    {
+        return Ok(());
+    }
+
+    let is_valid = GLOBAL_KEYWORDS
+        .iter()
+        .chain(allowed.iter())
+        .any(|m| m.eq_ignore_ascii_case(keyword));
+
+    if is_valid {
+        return Ok(());
+    }
+
+    Err(Error::Validation {
+        reason: format!(
+            "invalid value `{}` for property `{}`, expected one of: {}",
+            keyword,
+            name,
+            allowed.join(", ")
+        ),
+    })
+}
+
+impl StyleAttribute {
+    /// Checks this style attribute against the bundled database of CSS properties.
+    pub fn validate(&self) -> Result<()> {
+        validate_property(&self.key)?;
+
+        if self.value.iter().any(|m| m.inner.contains("${")) {
+            return Ok(());
+        }
+
+        // Values that cannot be understood by the typed value layer are not validated.
+        match self.typed_value() {
+            Ok(m) => validate_value(&self.key, &m),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl RuleBlockContent {
+    fn first_invalid_attribute(&self) -> Option<(&StyleAttribute, Error)> {
+        match self {
+            Self::StyleAttr(ref m) => m.validate().err().map(|e| (m, e)),
+            Self::Rule(ref m) => m.content.iter().find_map(|m| m.first_invalid_attribute()),
+            Self::Block(ref m) => m.content.iter().find_map(|m| m.first_invalid_attribute()),
+        }
+    }
+}
+
+impl Sheet {
+    /// Checks all style attributes in this stylesheet against the bundled database of CSS
+    /// properties.
+    ///
+    /// Returns the error for the first invalid style attribute.
+    pub fn validate(&self) -> Result<()> {
+        match self.first_invalid_attribute() {
+            Some((_, e)) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the first invalid style attribute in this stylesheet and its error.
+    ///
+    /// Use [`find_declaration`] to locate the attribute in the source of the stylesheet.
+    pub fn first_invalid_attribute(&self) -> Option<(&StyleAttribute, Error)> {
+        self.iter()
+            .flat_map(|m| match m {
+                ScopeContent::Block(ref m) => m.content.iter(),
+                ScopeContent::Rule(ref m) => m.content.iter(),
+            })
+            .find_map(|m| m.first_invalid_attribute())
+    }
+}
+
+/// Returns the byte offset of the first declaration of property `name` in `source`, if any.
+///
+/// A declaration is the property name at the start of the source or after `{`, `;` or a comment,
+/// followed by `:`.
+pub fn find_declaration(source: &str, name: &str) -> Option<usize> {
+    source.match_indices(name).map(|(i, _)| i).find(|i| {
+        let before = source[..*i].trim_end();
+        let after = source[i + name.len()..].trim_start();
+
+        (before.is_empty() || before.ends_with(['{', ';', '/'])) && after.starts_with(':')
+    })
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let next = (prev + usize::from(ca != *cb))
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            prev = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_database_sorted() {
+        assert!(PROPERTIES.windows(2).all(|m| m[0] < m[1]));
+        assert!(KEYWORDS.windows(2).all(|m| m[0].0 < m[1].0));
+    }
+
+    #[test]
+    fn test_property_names() {
+        assert!(validate_property("background-color").is_ok());
+        assert!(validate_property("--primary-color").is_ok());
+        assert!(validate_property("-webkit-appearance").is_ok());
+        assert!(validate_property("container-type").is_ok());
+        assert!(validate_property("view-transition-name").is_ok());
+
+        assert_eq!(
+            validate_property("backgroud-color"),
+            Err(Error::Validation {
+                reason: "unknown property `backgroud-color`, did you mean `background-color`?"
+                    .to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_allowed_properties() {
+        assert!(is_allowed_by("my-property, other", "my-property"));
+        assert!(is_allowed_by("my-property, other", "OTHER"));
+        assert!(is_allowed_by("*", "anything"));
+
+        assert!(!is_allowed_by("", "my-property"));
+        assert!(!is_allowed_by("my-property", "other"));
+    }
+
+    #[test]
+    fn test_property_values() {
+        let attr = |key: &'static str, value: &'static str| StyleAttribute {
+            key: key.into(),
+            value: vec![value.into()].into(),
+        };
+
+        assert!(attr("display", "flex").validate().is_ok());
+        assert!(attr("display", "inherit").validate().is_ok());
+        assert!(attr("display", "${display}").validate().is_ok());
+        assert!(attr("width", "100px").validate().is_ok());
+        assert!(attr("display", "flexbox").validate().is_err());
+
+        assert!(attr("display", "-webkit-box").validate().is_ok());
+        assert!(attr("position", "-webkit-sticky").validate().is_ok());
+    }
+
+    #[test]
+    fn test_sheet() {
+        let sheet: Sheet = r#"
+            color: red;
+
+            @media screen and (max-width: 500px) {
+                .inner {
+                    positon: absolute;
+                }
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        assert!(sheet.validate().is_err());
+
+        let (attr, _) = sheet
+            .first_invalid_attribute()
+            .expect("Failed to find invalid attribute.");
+        assert_eq!(attr.key, "positon");
+    }
+
+    #[test]
+    fn test_find_declaration() {
+        let source = ".positon { color: red; } .a { positon : absolute; }";
+
+        assert_eq!(find_declaration(source, "positon"), Some(30));
+        assert_eq!(find_declaration("positon: absolute;", "positon"), Some(0));
+        assert_eq!(find_declaration("color: red;", "positon"), None);
+    }
+}
@@ -4,5 +4,7 @@ use quote::quote;
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
     let sheet_tokens = crate::sheet::macro_fn(input);
 
-    quote! { ::stylist::StyleSource::from(#sheet_tokens) }
+    quote! {
+        ::stylist::macros::collect_dependencies(|| #sheet_tokens).with_scoped_keyframes()
+    }
 }
@@ -0,0 +1,169 @@
+//! `css_module!(pub struct CardStyles, ".title { ... }", name = value)`
+//!
+//! Generates a struct with a field for each local class of the stylesheet.
+
+use std::collections::HashMap;
+
+use proc_macro2::{TokenStream, TokenTree};
+use proc_macro_error::abort;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Ident, LitStr, Token, Visibility};
+
+use stylist_core::ast::Sheet;
+
+use crate::literal::{parse_args, reify_str};
+
+#[derive(Debug)]
+pub struct CssModule {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    source: LitStr,
+    args: TokenStream,
+}
+
+impl Parse for CssModule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let source = input.parse()?;
+        let args = input.parse()?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            source,
+            args,
+        })
+    }
+}
+
+/// Returns the field name of a class, e.g.: `footer_link` for `footer-link`.
+///
+/// Keywords are escaped as raw identifiers, e.g.: `r#type` for `type`.
+fn field_ident(source: &LitStr, class: &str) -> Ident {
+    let name = class.replace('-', "_");
+
+    let ident =
+        syn::parse_str::<Ident>(&name).or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", name)));
+
+    match ident {
+        Ok(mut m) => {
+            m.set_span(source.span());
+            m
+        }
+        Err(_) => abort!(
+            source,
+            "class `{}` cannot be used as a field name `{}`",
+            class,
+            name
+        ),
+    }
+}
+
+pub(crate) fn css_module_impl(module: CssModule) -> TokenStream {
+    let CssModule {
+        attrs,
+        vis,
+        name,
+        source,
+        args,
+    } = module;
+
+    let sheet: Sheet = match source.value().parse() {
+        Ok(m) => m,
+        Err(e) => abort!(source, "{}", e.to_string()),
+    };
+
+    let (sheet, classes) = sheet.localize_classes();
+
+    if classes.is_empty() {
+        abort!(source, "a css module must define at least one class");
+    }
+
+    let mut fields: HashMap<String, &str> = HashMap::new();
+    let field_idents = classes
+        .iter()
+        .map(|class| {
+            let ident = field_ident(&source, class);
+
+            if let Some(m) = fields.insert(ident.to_string(), class) {
+                abort!(
+                    source,
+                    "classes `{}` and `{}` have the same field name `{}`",
+                    m,
+                    class,
+                    ident
+                );
+            }
+
+            ident
+        })
+        .collect::<Vec<_>>();
+    let field_docs = classes
+        .iter()
+        .map(|m| format!("The local class name of `.{}`.", m));
+
+    let args = parse_args(args.into_iter());
+    let source_token = TokenTree::Literal(source.token());
+    let sheet_tokens = reify_str(&sheet.to_string(), &source_token, &args, |_, _, e| {
+        e.to_string()
+    });
+
+    quote! {
+        #(#attrs)*
+        #[derive(::std::fmt::Debug, ::std::clone::Clone)]
+        #vis struct #name {
+            #(
+                #[doc = #field_docs]
+                pub #field_idents: ::std::string::String,
+            )*
+            // Prefixed, so it does not collide with the field of a `.style` class.
+            __stylist_style: ::stylist::Style,
+        }
+
+        impl #name {
+            /// Registers the stylesheet of this module and creates its local class names.
+            pub fn new() -> ::stylist::Result<Self> {
+                Self::new_with_manager(::stylist::manager::StyleManager::default())
+            }
+
+            /// Registers the stylesheet of this module with a custom [`StyleManager`] and
+            /// creates its local class names.
+            ///
+            /// [`StyleManager`]: ::stylist::manager::StyleManager
+            pub fn new_with_manager<M>(manager: M) -> ::stylist::Result<Self>
+            where
+                M: ::std::convert::Into<::stylist::manager::StyleManager>,
+            {
+                let source = ::stylist::StyleSource::from(#sheet_tokens).with_scoped_keyframes();
+                let style = ::stylist::Style::new_with_manager(source, manager)?;
+                let class_name = style.get_class_name();
+
+                ::std::result::Result::Ok(Self {
+                    #(
+                        #field_idents: ::std::format!("{}-{}", class_name, #classes),
+                    )*
+                    __stylist_style: style,
+                })
+            }
+
+            /// Returns the style that contains the stylesheet of this module.
+            pub fn style(&self) -> &::stylist::Style {
+                &self.__stylist_style
+            }
+        }
+    }
+}
+
+pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
+    match syn::parse2(input) {
+        Ok(m) => css_module_impl(m),
+        Err(e) => e.to_compile_error(),
+    }
+}
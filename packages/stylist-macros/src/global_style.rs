@@ -4,5 +4,5 @@ use quote::quote;
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
     let sheet_tokens = crate::sheet::macro_fn(input);
 
-    quote! { ::stylist::GlobalStyle::new(#sheet_tokens) }
+    quote! { ::stylist::GlobalStyle::new(::stylist::macros::collect_dependencies(|| #sheet_tokens)) }
 }
@@ -0,0 +1,109 @@
+//! `include_css!("path/to/file.css", name = value)`
+//!
+//! Reads a stylesheet relative to `CARGO_MANIFEST_DIR` at compile time and reifies it like a
+//! string literal passed to `css!`.
+
+use std::path::{Path, PathBuf};
+
+use proc_macro2::{TokenStream, TokenTree};
+use proc_macro_error::{abort, abort_call_site};
+use quote::quote;
+
+use litrs::StringLit;
+use std::convert::TryFrom;
+
+use stylist_core::line_column;
+
+use crate::literal::{parse_args, reify_str};
+
+/// Describes an error at `offset` of `part`, which is a slice of the content of the file at `path`.
+fn describe_error(
+    path: &Path,
+    content: &str,
+    part: &str,
+    offset: Option<usize>,
+    e: stylist_core::Error,
+) -> String {
+    match offset {
+        Some(m) => {
+            let part_offset = part.as_ptr() as usize - content.as_ptr() as usize;
+            let (line, column) = line_column(content, part_offset + m);
+
+            format!("{}:{}:{}: {}", path.display(), line, column, e)
+        }
+        None => format!("{}: {}", path.display(), e),
+    }
+}
+
+fn resolve_path(relative: &str) -> PathBuf {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(m) => Path::new(&m).join(relative),
+        None => PathBuf::from(relative),
+    }
+}
+
+pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+
+    let first_token: TokenTree = match tokens.next() {
+        Some(m) => m,
+        None => abort_call_site!("expected a path to a stylesheet"),
+    };
+
+    let path_literal = match StringLit::try_from(first_token.clone()) {
+        Ok(m) => m,
+        Err(e) => return e.to_compile_error2(),
+    };
+
+    let path = resolve_path(path_literal.value());
+    let content = match std::fs::read_to_string(&path) {
+        Ok(m) => m,
+        Err(e) => abort!(first_token, "failed to read {}: {}", path.display(), e),
+    };
+
+    let args = parse_args(tokens);
+    let sheet_tokens = reify_str(&content, &first_token, &args, |part, offset, e| {
+        describe_error(&path, &content, part, offset, e)
+    });
+
+    // Including the file makes cargo rebuild the crate when the stylesheet changes.
+    let path_str = path.to_string_lossy();
+    quote! {
+        {
+            const _: &str = ::std::include_str!(#path_str);
+            ::stylist::macros::collect_dependencies(|| #sheet_tokens).with_scoped_keyframes()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_error() {
+        let content = "color: red;\n.a {\n    color: blue;\n";
+        let e = content.parse::<stylist_core::ast::Sheet>().unwrap_err();
+
+        let offset = e.parse_offset(content);
+
+        let message = describe_error(Path::new("a.css"), content, content, offset, e);
+        assert!(message.starts_with("a.css:2:1: "), "{}", message);
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_describe_validation_error() {
+        use stylist_core::validation::find_declaration;
+
+        let content = "...${base};\n.a {\n    colour: red;\n}\n";
+        // The part of the content after the spread.
+        let part = &content[11..];
+        let sheet = part.parse::<stylist_core::ast::Sheet>().unwrap();
+        let (attr, e) = sheet.first_invalid_attribute().unwrap();
+        let offset = find_declaration(part, &attr.key);
+
+        let message = describe_error(Path::new("a.css"), content, part, offset, e);
+        assert!(message.starts_with("a.css:3:5: "), "{}", message);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::output::OutputFragment;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::{
     braced,
@@ -38,6 +38,18 @@ impl Parse for InterpolatedExpression {
 }
 
 impl InterpolatedExpression {
+    pub fn first_span(&self) -> Span {
+        self.dollar.spans[0]
+    }
+
+    pub fn last_span(&self) -> Span {
+        self.braces.span
+    }
+
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
     pub fn to_output_fragment(&self) -> OutputFragment {
         (*self.expr).clone().into()
     }
@@ -9,9 +9,12 @@
 //! In general, only a parse error in the first step should be fatal and panic immediately,
 //! while a parse error in the second step can recover and display a small precise error location
 //! to the user, then continue parsing the rest of the input.
-use super::css_ident::CssIdent;
-use crate::output::OutputFragment;
-use proc_macro2::TokenStream;
+use super::{css_ident::CssIdent, parse::fragment_spacing};
+use crate::{
+    output::OutputFragment,
+    spacing_iterator::{source_spacing, SpacedIterator},
+};
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::{
     parse::{Error as ParseError, Parse, ParseBuffer, Result as ParseResult},
@@ -79,6 +82,61 @@ impl ComponentValue {
 }
 
 impl ComponentValue {
+    /// Returns the span of the first token of this component value.
+    pub fn first_span(&self) -> Span {
+        match self {
+            Self::Token(token) => token.first_span(),
+            Self::Expr(expr) => expr.first_span(),
+            Self::Block(ref m) => m.kind.span(),
+            Self::Function(FunctionToken { name, .. }) => name.first_span(),
+        }
+    }
+
+    /// Returns the span of the last token of this component value.
+    pub fn last_span(&self) -> Span {
+        match self {
+            Self::Token(token) => token.last_span(),
+            Self::Expr(expr) => expr.last_span(),
+            Self::Block(ref m) => m.kind.span(),
+            Self::Function(FunctionToken { paren, .. }) => paren.span,
+        }
+    }
+
+    /// Returns whether `next` is separated from this value by whitespace.
+    ///
+    /// The whitespace of the macro input is used if span locations are available. Otherwise,
+    /// this falls back to guessing the spacing with [`fragment_spacing`].
+    pub fn is_spaced_from(&self, next: &Self) -> bool {
+        if let Some(m) = source_spacing(self.last_span(), next.first_span()) {
+            return m;
+        }
+
+        match (
+            self.to_output_fragments().last(),
+            next.to_output_fragments().first(),
+        ) {
+            (Some(l), Some(r)) => fragment_spacing(l, r).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Reifies a sequence of component values, inserting spaces where the input has them.
+    pub fn to_spaced_output(values: &[Self]) -> Vec<OutputFragment> {
+        values
+            .iter()
+            .map(Some)
+            // `None` marks a space.
+            .spaced_with(|l, r| match (l, r) {
+                (Some(l), Some(r)) if l.is_spaced_from(r) => Some(None),
+                _ => None,
+            })
+            .flat_map(|m| match m {
+                Some(m) => m.to_output_fragments(),
+                None => vec![' '.into()],
+            })
+            .collect()
+    }
+
     pub fn to_output_fragments(&self) -> Vec<OutputFragment> {
         match self {
             Self::Token(token) => {
@@ -95,9 +153,7 @@ impl ComponentValue {
                 }
                 let (start, end) = m.kind.surround_tokens();
                 let mut output = vec![start.into()];
-                for c in m.contents.iter() {
-                    output.extend(c.to_output_fragments());
-                }
+                output.extend(Self::to_spaced_output(&m.contents));
                 output.push(end.into());
                 output
             }
@@ -105,9 +161,7 @@ impl ComponentValue {
             Self::Function(FunctionToken { name, args, .. }) => {
                 // name( ... )
                 let mut output = vec![name.clone().into(), '('.into()];
-                for c in args {
-                    output.extend(c.to_output_fragments());
-                }
+                output.extend(Self::to_spaced_output(args));
                 output.push(')'.into());
                 output
             }
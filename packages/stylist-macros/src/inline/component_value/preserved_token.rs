@@ -1,5 +1,5 @@
 use super::super::css_ident::CssIdent;
-use proc_macro2::{Literal, Punct, TokenStream};
+use proc_macro2::{Literal, Punct, Span, TokenStream};
 use quote::ToTokens;
 use syn::parse::{Parse, ParseBuffer, Result as ParseResult};
 
@@ -35,6 +35,22 @@ impl Parse for PreservedToken {
 }
 
 impl PreservedToken {
+    pub fn first_span(&self) -> Span {
+        match self {
+            Self::Ident(i) => i.first_span(),
+            Self::Literal(l) => l.span(),
+            Self::Punct(p) => p.span(),
+        }
+    }
+
+    pub fn last_span(&self) -> Span {
+        match self {
+            Self::Ident(i) => i.last_span(),
+            Self::Literal(l) => l.span(),
+            Self::Punct(p) => p.span(),
+        }
+    }
+
     pub fn to_output_string(&self) -> String {
         match self {
             Self::Ident(i) => i.to_output_string(),
@@ -1,5 +1,5 @@
 use super::ComponentValue;
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::{
     braced, bracketed, parenthesized,
@@ -15,6 +15,15 @@ pub enum BlockKind {
 }
 
 impl BlockKind {
+    /// Returns the span of the whole block, including the delimiters.
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Braced(ref m) => m.span,
+            Self::Bracketed(ref m) => m.span,
+            Self::Paren(ref m) => m.span,
+        }
+    }
+
     pub fn surround_tokens(&self) -> (char, char) {
         match self {
             Self::Braced(_) => ('{', '}'),
@@ -1,4 +1,4 @@
-use proc_macro2::{Punct, Spacing, TokenStream};
+use proc_macro2::{Punct, Spacing, Span, TokenStream};
 use quote::ToTokens;
 use std::fmt::{Display, Formatter};
 use syn::{
@@ -7,6 +7,8 @@ use syn::{
     token, Ident,
 };
 
+use crate::spacing_iterator::source_spacing;
+
 syn::custom_punctuation!(DoubleSub, --);
 
 #[derive(Debug, Clone)]
@@ -38,13 +40,32 @@ impl Display for CssIdent {
 impl CssIdent {
     pub fn peek(lookahead: &ParseBuffer) -> bool {
         if lookahead.peek(token::Sub) {
+            // A dash followed by whitespace is not part of an identifier, e.g. in `100% - a`.
+            let is_joined = match lookahead.cursor().punct() {
+                Some((dash, rest)) => source_spacing(dash.span(), rest.span()) != Some(true),
+                None => true,
+            };
             // A single dash is not an identifier
-            lookahead.peek2(token::Sub) || lookahead.peek2(Ident::peek_any)
+            is_joined && (lookahead.peek2(token::Sub) || lookahead.peek2(Ident::peek_any))
         } else {
             IdentPart::peek(lookahead, true, true)
         }
     }
 
+    pub fn first_span(&self) -> Span {
+        self.parts
+            .first()
+            .expect("identifiers are never empty")
+            .span()
+    }
+
+    pub fn last_span(&self) -> Span {
+        self.parts
+            .last()
+            .expect("identifiers are never empty")
+            .span()
+    }
+
     pub fn to_output_string(&self) -> String {
         self.parts
             .iter()
@@ -57,6 +78,13 @@ impl CssIdent {
 }
 
 impl IdentPart {
+    fn span(&self) -> Span {
+        match self {
+            Self::Dash(d) => d.span(),
+            Self::Ident(i) => i.span(),
+        }
+    }
+
     fn parse_part(
         input: &ParseBuffer,
         accept_dash: bool,
@@ -89,6 +117,11 @@ impl Parse for CssIdent {
             if !IdentPart::peek(input, joins_dash, joins_idents) {
                 break;
             }
+            // Parts separated by whitespace in the source are not joined, e.g. in `100% - a`.
+            let last_span = parts.last().unwrap().span();
+            if source_spacing(last_span, input.span()) == Some(true) {
+                break;
+            }
             parts.push(IdentPart::parse_part(input, joins_dash, joins_idents)?);
         }
         Ok(Self { parts })
@@ -1,16 +1,17 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::{
     parse::{Error as ParseError, Parse, ParseBuffer, Result as ParseResult},
     spanned::Spanned,
     token,
 };
 
-use super::{fragment_spacing, IntoOutputContext};
+use super::IntoOutputContext;
 use crate::inline::component_value::{
     ComponentValue, ComponentValueStream, InterpolatedExpression, PreservedToken,
 };
 use crate::inline::css_ident::CssIdent;
-use crate::output::{OutputAttribute, OutputCowString};
-use crate::spacing_iterator::SpacedIterator;
+use crate::output::{OutputAttribute, OutputCowString, OutputFragment};
 
 #[derive(Debug)]
 pub enum CssAttributeName {
@@ -95,22 +96,69 @@ impl ComponentValue {
     }
 }
 
+impl ToTokens for CssAttributeValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for v in self.values.iter() {
+            v.to_tokens(tokens);
+        }
+    }
+}
+
 impl CssAttribute {
     pub(super) fn into_output(self, ctx: &mut IntoOutputContext) -> OutputAttribute {
+        let values: Vec<OutputFragment> = ComponentValue::to_spaced_output(&self.value.values);
+
+        #[cfg(feature = "validation")]
+        self.validate(&values, ctx);
+
         ctx.extend_errors(self.value.errors);
 
-        let values = self
-            .value
-            .values
-            .into_iter()
-            .flat_map(|p| p.to_output_fragments())
-            .spaced_with(fragment_spacing)
-            .collect();
         OutputAttribute {
             key: self.name.into_output(),
             values,
         }
     }
+
+    // Checks the attribute against the css property database of stylist-core.
+    #[cfg(feature = "validation")]
+    fn validate(&self, values: &[OutputFragment], ctx: &mut IntoOutputContext) {
+        use stylist_core::ast::Value;
+        use stylist_core::validation::{validate_property, validate_value};
+        use stylist_core::Error;
+
+        let reason = |e: Error| match e {
+            Error::Validation { reason } => reason,
+            e => e.to_string(),
+        };
+
+        let name = match self.name {
+            CssAttributeName::Identifier(ref m) => m,
+            // Interpolated names are only known at runtime.
+            CssAttributeName::Expr(_) => return,
+        };
+        let key = name.to_output_string();
+
+        if let Err(e) = validate_property(&key) {
+            ctx.push_error(ParseError::new_spanned(name, reason(e)));
+            return;
+        }
+
+        // Interpolated values are only known at runtime.
+        let value = values
+            .iter()
+            .map(|m| match m.clone().into_inner() {
+                OutputCowString::Str(s) => Some(s),
+                OutputCowString::Raw(..) => None,
+            })
+            .collect::<Option<String>>();
+
+        // Values that cannot be understood by the typed value layer are not validated.
+        if let Some(Ok(m)) = value.map(|m| Value::parse(&m)) {
+            if let Err(e) = validate_value(&key, &m) {
+                ctx.push_error(ParseError::new_spanned(&self.value, reason(e)));
+            }
+        }
+    }
 }
 
 impl CssAttributeName {
@@ -0,0 +1,81 @@
+use syn::{
+    braced,
+    parse::{Parse, ParseBuffer, Result as ParseResult},
+    token,
+};
+
+use super::{
+    super::component_value::{ComponentValue, ComponentValueStream},
+    CssScope, IntoOutputContext,
+};
+use crate::output::{OutputRule, OutputRuleBlockContent};
+
+/// A single keyframe block, such as `from { .. }` or `50% { .. }`.
+#[derive(Debug)]
+struct CssKeyframe {
+    selector: Vec<ComponentValue>,
+    scope: CssScope,
+}
+
+impl Parse for CssKeyframe {
+    fn parse(input: &ParseBuffer) -> ParseResult<Self> {
+        // Consume all tokens till the next '{'-block
+        let mut component_iter = ComponentValueStream::from(input);
+        let mut selector = vec![];
+        while !input.peek(token::Brace) {
+            let next_token = component_iter
+                .next()
+                .ok_or_else(|| input.error("Keyframe: unexpected end of input"))??;
+            selector.push(next_token);
+        }
+
+        if selector.is_empty() {
+            return Err(input.error("Keyframe: expected a keyframe selector"));
+        }
+
+        let scope = input.parse()?;
+        Ok(Self { selector, scope })
+    }
+}
+
+impl CssKeyframe {
+    fn into_output(self, ctx: &mut IntoOutputContext) -> OutputRule {
+        OutputRule {
+            condition: ComponentValue::to_spaced_output(&self.selector),
+            content: self.scope.into_rule_block_output(ctx),
+        }
+    }
+}
+
+/// The contents of a `@keyframes` rule.
+#[derive(Debug)]
+pub struct CssKeyframes {
+    _brace: token::Brace,
+    frames: Vec<CssKeyframe>,
+}
+
+impl Parse for CssKeyframes {
+    fn parse(input: &ParseBuffer) -> ParseResult<Self> {
+        let inner;
+        let brace = braced!(inner in input);
+
+        let mut frames = Vec::new();
+        while !inner.is_empty() {
+            frames.push(inner.parse()?);
+        }
+
+        Ok(Self {
+            _brace: brace,
+            frames,
+        })
+    }
+}
+
+impl CssKeyframes {
+    pub fn into_output(self, ctx: &mut IntoOutputContext) -> Vec<OutputRuleBlockContent> {
+        self.frames
+            .into_iter()
+            .map(|m| OutputRuleBlockContent::Rule(Box::new(m.into_output(ctx))))
+            .collect()
+    }
+}
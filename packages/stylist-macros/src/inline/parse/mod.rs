@@ -5,19 +5,25 @@ use crate::output::OutputFragment;
 
 mod attribute;
 mod block;
+mod keyframes;
 mod qualifier;
 mod root;
 mod rule;
 mod scope;
 mod scope_content;
+mod selector;
+mod spread;
 
 pub use attribute::{CssAttribute, CssAttributeName, CssAttributeValue};
 pub use block::CssQualifiedRule;
+pub use keyframes::CssKeyframes;
 pub use qualifier::CssBlockQualifier;
 pub use root::CssRootNode;
 pub use rule::CssAtRule;
 pub use scope::CssScope;
 pub use scope_content::CssScopeContent;
+pub use selector::CssSelector;
+pub use spread::CssSpread;
 
 #[derive(Debug, Default)]
 pub struct IntoOutputContext {
@@ -1,9 +1,8 @@
 use super::{
-    super::component_value::{ComponentValue, ComponentValueStream, PreservedToken},
-    fragment_spacing, IntoOutputContext,
+    super::component_value::{ComponentValue, ComponentValueStream},
+    CssSelector, IntoOutputContext,
 };
-use crate::{output::OutputSelector, spacing_iterator::SpacedIterator};
-use itertools::Itertools;
+use crate::output::OutputSelector;
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::{
@@ -14,6 +13,7 @@ use syn::{
 #[derive(Debug, Clone, Default)]
 pub struct CssBlockQualifier {
     qualifiers: Vec<ComponentValue>,
+    selectors: Vec<CssSelector>,
     errors: Vec<ParseError>,
 }
 
@@ -36,7 +36,21 @@ impl Parse for CssBlockQualifier {
             }
             errors.extend(token_errors);
         }
-        Ok(Self { qualifiers, errors })
+
+        // Only parse the structure if all tokens are valid to avoid reporting the same error twice.
+        let selectors = if errors.is_empty() {
+            let (selectors, selector_errors) = CssSelector::parse_list(&qualifiers);
+            errors.extend(selector_errors);
+            selectors
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            qualifiers,
+            selectors,
+            errors,
+        })
     }
 }
 
@@ -52,30 +66,6 @@ impl CssBlockQualifier {
     pub fn into_output(self, ctx: &mut IntoOutputContext) -> Vec<OutputSelector> {
         ctx.extend_errors(self.errors);
 
-        fn is_not_comma(q: &ComponentValue) -> bool {
-            !matches!(q, ComponentValue::Token(PreservedToken::Punct(ref p)) if p.as_char() == ',')
-        }
-
-        self.qualifiers
-            .into_iter()
-            .peekable()
-            .batching(|it| {
-                // Return if no items left
-                it.peek()?;
-                // Take until the next comma
-                let selector_parts = it
-                    .peeking_take_while(is_not_comma)
-                    // reify the individual parts
-                    .flat_map(|p| p.to_output_fragments())
-                    // space them correctly
-                    .spaced_with(fragment_spacing)
-                    .collect();
-                let selector = OutputSelector {
-                    selectors: selector_parts,
-                };
-                it.next(); // Consume the comma
-                Some(selector)
-            })
-            .collect()
+        self.selectors.iter().map(|m| m.to_output()).collect()
     }
 }
@@ -1,7 +1,7 @@
 use std::mem;
 
 use super::{CssAttribute, CssQualifiedRule, CssScopeContent, IntoOutputContext};
-use crate::output::{OutputScopeContent, OutputSheet};
+use crate::output::{OutputScopeContent, OutputSheet, OutputSheetContent};
 use syn::parse::{Parse, ParseBuffer, Result as ParseResult};
 
 #[derive(Debug)]
@@ -22,12 +22,16 @@ impl CssRootNode {
 
         let mut attrs: Vec<CssAttribute> = Vec::new();
         let flush_attrs = |attrs: &mut Vec<CssAttribute>,
-                           contents: &mut Vec<OutputScopeContent>,
+                           contents: &mut Vec<OutputSheetContent>,
                            ctx: &mut IntoOutputContext| {
             if !attrs.is_empty() {
-                contents.push(OutputScopeContent::Block(
-                    CssQualifiedRule::into_dangling_output(mem::take(attrs), ctx),
-                ));
+                contents.push(
+                    OutputScopeContent::Block(CssQualifiedRule::into_dangling_output(
+                        mem::take(attrs),
+                        ctx,
+                    ))
+                    .into(),
+                );
             }
         };
 
@@ -36,12 +40,17 @@ impl CssRootNode {
                 CssScopeContent::Attribute(m) => attrs.push(m),
                 CssScopeContent::AtRule(m) => {
                     flush_attrs(&mut attrs, &mut contents, ctx);
-                    contents.push(OutputScopeContent::Rule(m.into_rule_output(ctx)));
+                    contents.push(OutputScopeContent::Rule(m.into_rule_output(ctx)).into());
                 }
 
                 CssScopeContent::Nested(m) => {
                     flush_attrs(&mut attrs, &mut contents, ctx);
-                    contents.push(OutputScopeContent::Block(m.into_output(ctx)));
+                    contents.push(OutputScopeContent::Block(m.into_output(ctx)).into());
+                }
+
+                CssScopeContent::Spread(m) => {
+                    flush_attrs(&mut attrs, &mut contents, ctx);
+                    contents.push(OutputSheetContent::Spread(m.into_output()));
                 }
             }
         }
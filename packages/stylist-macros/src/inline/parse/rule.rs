@@ -8,16 +8,14 @@ use super::{
         component_value::{ComponentValue, ComponentValueStream},
         css_ident::CssIdent,
     },
-    fragment_spacing, CssScope, IntoOutputContext,
-};
-use crate::{
-    output::{OutputFragment, OutputRule},
-    spacing_iterator::SpacedIterator,
+    CssKeyframes, CssScope, IntoOutputContext,
 };
+use crate::output::{OutputFragment, OutputRule};
 
 #[derive(Debug)]
 pub enum CssAtRuleContent {
     Scope(CssScope),
+    Keyframes(CssKeyframes),
     Empty(token::Semi),
 }
 
@@ -42,7 +40,9 @@ impl Parse for CssAtRule {
 
         // Recognize the type of @-rule
         // TODO: be sensitive to this detected type when validating the prelude and contained attributes
-        if !["media", "supports"].contains(&name.to_output_string().as_str()) {
+        let name_str = name.to_output_string();
+        let is_keyframes = ["keyframes", "-webkit-keyframes"].contains(&name_str.as_str());
+        if !is_keyframes && !["media", "supports"].contains(&name_str.as_str()) {
             errors.push(ParseError::new_spanned(
                 &name,
                 format!("@-rule '{}' not supported", name),
@@ -54,6 +54,10 @@ impl Parse for CssAtRule {
                 let semi = input.parse()?;
                 break CssAtRuleContent::Empty(semi);
             }
+            if input.peek(token::Brace) && is_keyframes {
+                let frames = input.parse()?;
+                break CssAtRuleContent::Keyframes(frames);
+            }
             if input.peek(token::Brace) {
                 let scope = input.parse()?;
                 break CssAtRuleContent::Scope(scope);
@@ -80,13 +84,7 @@ impl CssAtRule {
             "@{} ",
             self.name.to_output_string()
         ))];
-        prelude.extend(
-            self.prelude
-                .clone()
-                .into_iter()
-                .flat_map(|p| p.to_output_fragments())
-                .spaced_with(fragment_spacing),
-        );
+        prelude.extend(ComponentValue::to_spaced_output(&self.prelude));
 
         prelude
     }
@@ -99,6 +97,7 @@ impl CssAtRule {
             condition,
             content: match self.contents {
                 CssAtRuleContent::Scope(m) => m.into_rule_output(ctx),
+                CssAtRuleContent::Keyframes(m) => m.into_output(ctx),
                 CssAtRuleContent::Empty(_) => Vec::new(),
             },
         }
@@ -112,6 +111,7 @@ impl CssAtRule {
             condition,
             content: match self.contents {
                 CssAtRuleContent::Scope(m) => m.into_rule_block_output(ctx),
+                CssAtRuleContent::Keyframes(m) => m.into_output(ctx),
                 CssAtRuleContent::Empty(_) => Vec::new(),
             },
         }
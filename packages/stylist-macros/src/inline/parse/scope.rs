@@ -55,6 +55,12 @@ impl CssScope {
                     flush_attrs(&mut attrs, &mut contents, ctx);
                     contents.push(OutputRuleBlockContent::Block(Box::new(m.into_output(ctx))));
                 }
+                CssScopeContent::Spread(m) => {
+                    ctx.push_error(ParseError::new_spanned(
+                        m,
+                        "Can only spread sources at the top level",
+                    ));
+                }
             }
         }
 
@@ -87,6 +93,13 @@ impl CssScope {
                         "Can not nest qualified blocks (yet)",
                     ));
                 }
+
+                CssScopeContent::Spread(m) => {
+                    ctx.push_error(ParseError::new_spanned(
+                        m,
+                        "Can only spread sources at the top level",
+                    ));
+                }
             }
         }
 
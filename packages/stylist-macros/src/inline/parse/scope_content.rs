@@ -1,9 +1,9 @@
-use itertools::Itertools;
+use itertools::{Itertools, MultiPeek};
 use syn::parse::{Parse, ParseBuffer, Result as ParseResult};
 
 use super::{
-    super::component_value::{ComponentValue, ComponentValueStream, PreservedToken},
-    CssAtRule, CssAttribute, CssQualifiedRule,
+    super::component_value::{BlockKind, ComponentValue, ComponentValueStream, PreservedToken},
+    CssAtRule, CssAttribute, CssQualifiedRule, CssSpread,
 };
 
 #[derive(Debug)]
@@ -11,10 +11,16 @@ pub enum CssScopeContent {
     Attribute(CssAttribute),
     AtRule(CssAtRule),
     Nested(CssQualifiedRule),
+    Spread(CssSpread),
 }
 
 impl Parse for CssScopeContent {
     fn parse(input: &ParseBuffer) -> ParseResult<Self> {
+        if CssSpread::peek(input) {
+            let spread = input.parse()?;
+            return Ok(Self::Spread(spread));
+        }
+
         // Fork the stream. Peeking a component value might still consume tokens from the stream!
         let forked_input = input.fork();
         let mut component_peek = ComponentValueStream::from(&forked_input).multipeek();
@@ -36,7 +42,7 @@ impl Parse for CssScopeContent {
             // peek another token to see if it's colon
             let maybe_colon = component_peek.peek();
             if let Some(Ok(ComponentValue::Token(PreservedToken::Punct(p)))) = maybe_colon {
-                if p.as_char() == ':' {
+                if p.as_char() == ':' && !Self::peek_qualified_rule(&mut component_peek) {
                     let attr = input.parse()?;
                     return Ok(Self::Attribute(attr));
                 }
@@ -49,6 +55,34 @@ impl Parse for CssScopeContent {
 }
 
 impl CssScopeContent {
+    // A selector with a pseudo-class, such as `a:hover { .. }`, starts like an attribute.
+    // It is a qualified rule if a block follows before the next `;`. A block in an attribute
+    // value is followed by `;` and reported as an error when parsing the attribute.
+    fn peek_qualified_rule<I>(component_peek: &mut MultiPeek<I>) -> bool
+    where
+        I: Iterator<Item = ParseResult<ComponentValue>>,
+    {
+        fn is_semicolon(value: Option<&ParseResult<ComponentValue>>) -> bool {
+            matches!(
+                value,
+                Some(Ok(ComponentValue::Token(PreservedToken::Punct(ref p)))) if p.as_char() == ';'
+            )
+        }
+
+        loop {
+            match component_peek.peek() {
+                None | Some(Err(_)) => return false,
+                m if is_semicolon(m) => return false,
+                Some(Ok(ComponentValue::Block(ref m)))
+                    if matches!(m.kind, BlockKind::Braced(_)) =>
+                {
+                    return !is_semicolon(component_peek.peek());
+                }
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+
     // §5.4.1: Consume a list of rules
     pub fn consume_list_of_rules(input: &ParseBuffer) -> ParseResult<Vec<Self>> {
         let mut contents = Vec::new();
@@ -0,0 +1,475 @@
+//! A structural parser for selectors.
+//!
+//! A selector is a sequence of compound selectors (`a.link:hover`) joined by combinators
+//! (whitespace, `>`, `+`, `~` and `||`). Parsing this structure, instead of joining tokens, means
+//! the spacing of the output does not depend on guessing from token adjacency.
+use std::mem;
+
+use syn::parse::Error as ParseError;
+
+use super::super::{
+    component_value::{
+        BlockKind, ComponentValue, FunctionToken, InterpolatedExpression, PreservedToken,
+    },
+    css_ident::CssIdent,
+};
+use crate::output::{OutputFragment, OutputSelector};
+
+/// Functional pseudo-classes and pseudo-elements that take a list of selectors.
+const SELECTOR_LIST_PSEUDOS: &[&str] = &[
+    "-moz-any",
+    "-webkit-any",
+    "any",
+    "cue",
+    "has",
+    "host",
+    "host-context",
+    "is",
+    "matches",
+    "not",
+    "slotted",
+    "where",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+    Column,
+}
+
+impl Combinator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Descendant => " ",
+            Self::Child => " > ",
+            Self::NextSibling => " + ",
+            Self::SubsequentSibling => " ~ ",
+            Self::Column => " || ",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AttributeSelector {
+    name: ComponentValue,
+    matcher: Option<(&'static str, ComponentValue)>,
+    modifier: Option<CssIdent>,
+}
+
+#[derive(Debug, Clone)]
+enum PseudoArgs {
+    Selectors(Vec<CssSelector>),
+    Raw(Vec<ComponentValue>),
+}
+
+#[derive(Debug, Clone)]
+enum SimpleSelector {
+    // &
+    Nesting,
+    // *
+    Universal,
+    // div
+    Type(CssIdent),
+    // .class, .${expr}
+    Class(ComponentValue),
+    // #id, #${expr}
+    Id(ComponentValue),
+    // [name^="value" i]
+    Attribute(AttributeSelector),
+    // :hover, ::before, :not(.a), :nth-child(2n + 1)
+    Pseudo {
+        is_element: bool,
+        name: CssIdent,
+        args: Option<PseudoArgs>,
+    },
+    // ${expr}
+    Expr(InterpolatedExpression),
+}
+
+#[derive(Debug, Clone)]
+enum SelectorPart {
+    Compound(Vec<SimpleSelector>),
+    Combinator(Combinator),
+}
+
+#[derive(Debug, Clone)]
+pub struct CssSelector {
+    parts: Vec<SelectorPart>,
+}
+
+fn is_punct(value: Option<&ComponentValue>, c: char) -> bool {
+    matches!(value, Some(ComponentValue::Token(PreservedToken::Punct(ref p))) if p.as_char() == c)
+}
+
+struct SelectorParser<'a> {
+    values: &'a [ComponentValue],
+    pos: usize,
+    errors: Vec<ParseError>,
+}
+
+impl<'a> SelectorParser<'a> {
+    fn new(values: &'a [ComponentValue]) -> Self {
+        Self {
+            values,
+            pos: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<&'a ComponentValue> {
+        self.values.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<&'a ComponentValue> {
+        let value = self.peek()?;
+        self.pos += 1;
+        Some(value)
+    }
+
+    fn error(&mut self, value: &ComponentValue, message: &str) {
+        self.errors.push(ParseError::new_spanned(value, message));
+    }
+
+    fn selector(&mut self) -> CssSelector {
+        let mut parts = Vec::new();
+        let mut compound = Vec::new();
+
+        while let Some(value) = self.peek() {
+            if let Some(combinator) = self.combinator() {
+                if !compound.is_empty() {
+                    parts.push(SelectorPart::Compound(mem::take(&mut compound)));
+                } else if matches!(parts.last(), Some(SelectorPart::Combinator(_))) {
+                    self.error(value, "expected a selector before this combinator");
+                }
+                parts.push(SelectorPart::Combinator(combinator));
+                continue;
+            }
+
+            // Compound selectors separated by whitespace are joined by the descendant combinator.
+            if !compound.is_empty() && self.values[self.pos - 1].is_spaced_from(value) {
+                parts.push(SelectorPart::Compound(mem::take(&mut compound)));
+                parts.push(SelectorPart::Combinator(Combinator::Descendant));
+            }
+
+            if let Some(m) = self.simple_selector() {
+                compound.push(m);
+            }
+        }
+
+        if !compound.is_empty() {
+            parts.push(SelectorPart::Compound(compound));
+        } else if let Some(SelectorPart::Combinator(_)) = parts.last() {
+            let last = self.values.last().expect("a combinator has been parsed");
+            self.error(last, "expected a selector after this combinator");
+        }
+
+        CssSelector { parts }
+    }
+
+    fn combinator(&mut self) -> Option<Combinator> {
+        let combinator = match self.peek()? {
+            ComponentValue::Token(PreservedToken::Punct(ref p)) => match p.as_char() {
+                '>' => Combinator::Child,
+                '+' => Combinator::NextSibling,
+                '~' => Combinator::SubsequentSibling,
+                '|' if is_punct(self.values.get(self.pos + 1), '|') => {
+                    self.pos += 1;
+                    Combinator::Column
+                }
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.pos += 1;
+
+        Some(combinator)
+    }
+
+    fn simple_selector(&mut self) -> Option<SimpleSelector> {
+        let value = self.next()?;
+
+        match value {
+            ComponentValue::Token(PreservedToken::Punct(ref p)) => match p.as_char() {
+                '&' => Some(SimpleSelector::Nesting),
+                '*' => Some(SimpleSelector::Universal),
+                '.' => self
+                    .name(value, "expected a class name after `.`")
+                    .map(SimpleSelector::Class),
+                '#' => self
+                    .name(value, "expected an id after `#`")
+                    .map(SimpleSelector::Id),
+                ':' => self.pseudo(value),
+                _ => {
+                    self.error(value, "unexpected punctuation in selector");
+                    None
+                }
+            },
+            ComponentValue::Token(PreservedToken::Ident(ref m)) => {
+                Some(SimpleSelector::Type(m.clone()))
+            }
+            ComponentValue::Token(PreservedToken::Literal(_)) => {
+                self.error(
+                    value,
+                    "string literals are only allowed in attribute selectors",
+                );
+                None
+            }
+            ComponentValue::Expr(ref m) => Some(SimpleSelector::Expr(m.clone())),
+            ComponentValue::Block(ref m) => match m.kind {
+                BlockKind::Bracketed(_) => self.attribute(value, &m.contents),
+                _ => {
+                    self.error(
+                        value,
+                        "expected a valid part of a scope qualifier, not a block",
+                    );
+                    None
+                }
+            },
+            ComponentValue::Function(_) => {
+                self.error(value, "expected `:` before a functional pseudo-class");
+                None
+            }
+        }
+    }
+
+    fn name(&mut self, after: &ComponentValue, message: &str) -> Option<ComponentValue> {
+        match self.peek() {
+            Some(m @ ComponentValue::Token(PreservedToken::Ident(_)))
+            | Some(m @ ComponentValue::Expr(_)) => {
+                self.pos += 1;
+                Some(m.clone())
+            }
+            _ => {
+                self.error(after, message);
+                None
+            }
+        }
+    }
+
+    fn pseudo(&mut self, colon: &ComponentValue) -> Option<SimpleSelector> {
+        let is_element = is_punct(self.peek(), ':');
+        if is_element {
+            self.pos += 1;
+        }
+
+        match self.peek() {
+            Some(ComponentValue::Token(PreservedToken::Ident(ref name))) => {
+                self.pos += 1;
+                Some(SimpleSelector::Pseudo {
+                    is_element,
+                    name: name.clone(),
+                    args: None,
+                })
+            }
+            Some(ComponentValue::Function(ref m)) => {
+                self.pos += 1;
+                Some(SimpleSelector::Pseudo {
+                    is_element,
+                    name: m.name.clone(),
+                    args: Some(self.pseudo_args(m)),
+                })
+            }
+            _ => {
+                self.error(
+                    colon,
+                    "expected the name of a pseudo-class or a pseudo-element",
+                );
+                None
+            }
+        }
+    }
+
+    fn pseudo_args(&mut self, function: &FunctionToken) -> PseudoArgs {
+        let name = function.name.to_output_string().to_ascii_lowercase();
+
+        if SELECTOR_LIST_PSEUDOS.contains(&name.as_str()) {
+            let (selectors, errors) = CssSelector::parse_list(&function.args);
+            self.errors.extend(errors);
+            PseudoArgs::Selectors(selectors)
+        } else {
+            PseudoArgs::Raw(function.args.clone())
+        }
+    }
+
+    fn attribute(
+        &mut self,
+        block: &ComponentValue,
+        contents: &[ComponentValue],
+    ) -> Option<SimpleSelector> {
+        match SelectorParser::new(contents).attribute_contents() {
+            Some(m) => Some(SimpleSelector::Attribute(m)),
+            None => {
+                self.error(
+                    block,
+                    "expected an attribute selector, such as `[name]` or `[name=\"value\"]`",
+                );
+                None
+            }
+        }
+    }
+
+    fn attribute_contents(&mut self) -> Option<AttributeSelector> {
+        let name = match self.next()? {
+            m @ ComponentValue::Token(PreservedToken::Ident(_)) | m @ ComponentValue::Expr(_) => {
+                m.clone()
+            }
+            _ => return None,
+        };
+
+        if self.peek().is_none() {
+            return Some(AttributeSelector {
+                name,
+                matcher: None,
+                modifier: None,
+            });
+        }
+
+        let operator = self.attribute_operator()?;
+        let value = match self.next()? {
+            m @ ComponentValue::Token(PreservedToken::Ident(_))
+            | m @ ComponentValue::Token(PreservedToken::Literal(_))
+            | m @ ComponentValue::Expr(_) => m.clone(),
+            _ => return None,
+        };
+        let modifier = match self.next() {
+            Some(ComponentValue::Token(PreservedToken::Ident(ref m))) => Some(m.clone()),
+            Some(_) => return None,
+            None => None,
+        };
+
+        if self.peek().is_some() {
+            return None;
+        }
+
+        Some(AttributeSelector {
+            name,
+            matcher: Some((operator, value)),
+            modifier,
+        })
+    }
+
+    fn attribute_operator(&mut self) -> Option<&'static str> {
+        if is_punct(self.peek(), '=') {
+            self.pos += 1;
+            return Some("=");
+        }
+
+        let operator = ["~=", "|=", "^=", "$=", "*="].iter().find(|m| {
+            is_punct(self.peek(), m.chars().next().unwrap())
+                && is_punct(self.values.get(self.pos + 1), '=')
+        })?;
+        self.pos += 2;
+
+        Some(operator)
+    }
+}
+
+impl SimpleSelector {
+    fn to_output_fragments(&self) -> Vec<OutputFragment> {
+        match self {
+            Self::Nesting => vec![OutputFragment::Str("&".into())],
+            Self::Universal => vec![OutputFragment::Str("*".into())],
+            Self::Type(ref m) => vec![m.clone().into()],
+            Self::Class(ref m) => {
+                let mut output = vec![OutputFragment::Str(".".into())];
+                output.extend(m.to_output_fragments());
+                output
+            }
+            Self::Id(ref m) => {
+                let mut output = vec![OutputFragment::Str("#".into())];
+                output.extend(m.to_output_fragments());
+                output
+            }
+            Self::Attribute(ref m) => {
+                let mut output = vec!['['.into()];
+                output.extend(m.name.to_output_fragments());
+                if let Some((operator, ref value)) = m.matcher {
+                    output.push(OutputFragment::Str(operator.into()));
+                    output.extend(value.to_output_fragments());
+                }
+                if let Some(ref modifier) = m.modifier {
+                    output.push(' '.into());
+                    output.push(modifier.clone().into());
+                }
+                output.push(']'.into());
+                output
+            }
+            Self::Pseudo {
+                is_element,
+                ref name,
+                ref args,
+            } => {
+                let colons = if *is_element { "::" } else { ":" };
+                let mut output = vec![OutputFragment::Str(colons.into()), name.clone().into()];
+
+                match args {
+                    Some(PseudoArgs::Selectors(ref m)) => {
+                        output.push('('.into());
+                        for (i, selector) in m.iter().enumerate() {
+                            if i > 0 {
+                                output.push(OutputFragment::Str(", ".into()));
+                            }
+                            output.extend(selector.to_output().selectors);
+                        }
+                        output.push(')'.into());
+                    }
+                    Some(PseudoArgs::Raw(ref m)) => {
+                        output.push('('.into());
+                        output.extend(ComponentValue::to_spaced_output(m));
+                        output.push(')'.into());
+                    }
+                    None => {}
+                }
+
+                output
+            }
+            Self::Expr(ref m) => vec![m.to_output_fragment().into_selector()],
+        }
+    }
+}
+
+impl CssSelector {
+    /// Parses a comma separated list of selectors.
+    pub fn parse_list(values: &[ComponentValue]) -> (Vec<Self>, Vec<ParseError>) {
+        let mut selectors = Vec::new();
+        let mut errors = Vec::new();
+
+        for values in values.split(|m| is_punct(Some(m), ',')) {
+            if values.is_empty() {
+                continue;
+            }
+
+            let mut parser = SelectorParser::new(values);
+            selectors.push(parser.selector());
+            errors.extend(parser.errors);
+        }
+
+        (selectors, errors)
+    }
+
+    pub fn to_output(&self) -> OutputSelector {
+        let mut selectors = Vec::new();
+
+        for (i, part) in self.parts.iter().enumerate() {
+            match part {
+                SelectorPart::Compound(ref m) => {
+                    selectors.extend(m.iter().flat_map(|m| m.to_output_fragments()));
+                }
+                SelectorPart::Combinator(ref m) => {
+                    // A relative selector (`> a`) has no space before the leading combinator.
+                    let s = if i == 0 {
+                        m.as_str().trim_start()
+                    } else {
+                        m.as_str()
+                    };
+                    selectors.push(OutputFragment::Str(s.into()));
+                }
+            }
+        }
+
+        OutputSelector { selectors }
+    }
+}
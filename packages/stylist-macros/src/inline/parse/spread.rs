@@ -0,0 +1,48 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseBuffer, Result as ParseResult},
+    token, Token,
+};
+
+use super::super::component_value::InterpolatedExpression;
+
+/// Splices the contents of another source into the stylesheet, e.g.: `...${base};`.
+#[derive(Debug)]
+pub struct CssSpread {
+    dots: Token![...],
+    expr: InterpolatedExpression,
+    semi: Option<token::Semi>,
+}
+
+impl Parse for CssSpread {
+    fn parse(input: &ParseBuffer) -> ParseResult<Self> {
+        let dots = input.parse()?;
+        let expr = input.parse()?;
+        let semi = if input.peek(token::Semi) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self { dots, expr, semi })
+    }
+}
+
+impl ToTokens for CssSpread {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.dots.to_tokens(tokens);
+        self.expr.to_tokens(tokens);
+        self.semi.to_tokens(tokens);
+    }
+}
+
+impl CssSpread {
+    pub fn peek(input: &ParseBuffer) -> bool {
+        input.peek(Token![...])
+    }
+
+    pub fn into_output(self) -> TokenStream {
+        self.expr.expr().to_token_stream()
+    }
+}
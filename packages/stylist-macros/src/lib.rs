@@ -13,19 +13,23 @@ mod inline;
 mod literal;
 
 mod css;
+mod css_module;
 mod global_style;
+mod include_css;
 mod output;
 mod sheet;
 mod spacing_iterator;
 mod style;
+mod styled;
 mod styled_component;
 mod styled_component_impl;
+mod theme;
 mod use_style;
 
 #[proc_macro]
 #[proc_macro_error]
 pub fn sheet(input: TokenStream) -> TokenStream {
-    sheet::macro_fn(input.into()).into()
+    sheet::sheet_macro_fn(input.into()).into()
 }
 
 #[proc_macro]
@@ -46,12 +50,29 @@ pub fn css(input: TokenStream) -> TokenStream {
     css::macro_fn(input.into()).into()
 }
 
+#[proc_macro]
+#[proc_macro_error]
+pub fn css_module(input: TokenStream) -> TokenStream {
+    css_module::macro_fn(input.into()).into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+pub fn include_css(input: TokenStream) -> TokenStream {
+    include_css::macro_fn(input.into()).into()
+}
+
 #[proc_macro]
 #[proc_macro_error]
 pub fn use_style(input: TokenStream) -> TokenStream {
     use_style::macro_fn(input.into()).into()
 }
 
+#[proc_macro]
+pub fn styled(input: TokenStream) -> TokenStream {
+    styled::macro_fn(input)
+}
+
 #[proc_macro_attribute]
 pub fn styled_component(attr: TokenStream, item: TokenStream) -> TokenStream {
     styled_component::macro_fn(attr, item)
@@ -59,5 +80,26 @@ pub fn styled_component(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 #[proc_macro_attribute]
 pub fn styled_component_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
-    styled_component_impl::macro_fn(attr, item)
+    styled_component_impl::macro_fn(attr, item, styled_component_impl::yew_manager())
+}
+
+#[proc_macro_attribute]
+pub fn leptos_styled_component_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    styled_component_impl::macro_fn(attr, item, styled_component_impl::leptos_manager())
+}
+
+#[proc_macro_attribute]
+pub fn dioxus_styled_component_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    styled_component_impl::macro_fn(attr, item, styled_component_impl::dioxus_manager())
+}
+
+#[proc_macro_attribute]
+pub fn sycamore_styled_component_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
+    styled_component_impl::macro_fn(attr, item, styled_component_impl::sycamore_manager())
+}
+
+#[proc_macro_derive(Theme)]
+#[proc_macro_error]
+pub fn theme(input: TokenStream) -> TokenStream {
+    theme::macro_fn(syn::parse_macro_input!(input)).into()
 }
@@ -62,6 +62,8 @@ impl Parser {
     }
 
     /// Parse a string interpolation.
+    ///
+    /// The name is either an identifier or a path (`${Theme::primary}`).
     fn interpolation(i: &str) -> IResult<&str, Fragment, VerboseError<&str>> {
         #[cfg(test)]
         trace!("Interpolation: {}", i);
@@ -77,7 +79,7 @@ impl Parser {
                         "ArgumentName",
                         cut(Self::trimmed(recognize(preceded(
                             alpha1,
-                            many0(alt((alphanumeric1, tag("_")))),
+                            many0(alt((alphanumeric1, tag("_"), tag("::")))),
                         )))),
                     ),
                     tag("}"),
@@ -177,6 +179,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_path() -> Result<()> {
+        init();
+        let parsed = Parser::parse("color: ${Theme::FONT_COLOR};")?;
+
+        let expected = vec![
+            Fragment::Literal("color: ".to_string()),
+            Fragment::Interpolation("Theme::FONT_COLOR".to_string()),
+            Fragment::Literal(";".to_string()),
+        ];
+
+        assert_eq!(parsed, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_invalid() {
         init();
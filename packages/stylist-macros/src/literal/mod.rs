@@ -1,4 +1,5 @@
 use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
 
 use std::collections::{HashMap, HashSet};
 
@@ -7,15 +8,18 @@ use proc_macro_error::{abort, abort_call_site};
 use std::convert::TryFrom;
 
 use stylist_core::ast::Sheet;
+use stylist_core::line_column;
 
 pub mod argument;
 mod fstring;
+mod spread;
 mod to_output_with_args;
 
 use argument::Argument;
+use spread::{split_spreads, Part};
 use to_output_with_args::ToOutputWithArgs;
 
-use crate::output::{Reify, ReifyContext};
+use crate::output::{OutputSheet, OutputSheetContent, Reify, ReifyContext};
 
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
     let mut tokens = input.into_iter();
@@ -30,12 +34,37 @@ pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
         Err(e) => return e.to_compile_error2(),
     };
 
-    let sheet: Sheet = match s_literal.value().parse() {
-        Ok(m) => m,
+    let args = parse_args(tokens);
+    let literal = s_literal.value();
 
-        Err(e) => abort!(first_token, "{}", e.to_string()),
-    };
+    reify_str(literal, &first_token, &args, |part, offset, e| {
+        describe_error(literal, part, offset, e)
+    })
+}
+
+/// Describes an error at `offset` of `part`, a part of `literal` between spreads.
+fn describe_error(
+    literal: &str,
+    part: &str,
+    offset: Option<usize>,
+    e: stylist_core::Error,
+) -> String {
+    match offset {
+        Some(m) => {
+            let part_offset = part.as_ptr() as usize - literal.as_ptr() as usize;
+            let (line, column) = line_column(literal, part_offset + m);
+
+            format!("{} (at line {}, column {} of the literal)", e, line, column)
+        }
+        None => e.to_string(),
+    }
+}
 
+/// Parses the named arguments following the first argument of a macro, e.g.: `, color = color`.
+pub(crate) fn parse_args<I>(mut tokens: I) -> HashMap<String, Argument>
+where
+    I: Iterator<Item = TokenTree>,
+{
     let mut args = HashMap::new();
 
     let is_comma = |t: &TokenTree| -> bool {
@@ -115,9 +144,72 @@ pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
         }
     }
 
+    args
+}
+
+/// Parses `s` and reifies it into a sheet, substituting the named arguments.
+///
+/// Errors are reported at `first_token`, with the message returned by `describe_error` for the
+/// part of `s` that failed to parse or validate and the byte offset of the error in that part,
+/// if known.
+pub(crate) fn reify_str<F>(
+    s: &str,
+    first_token: &TokenTree,
+    args: &HashMap<String, Argument>,
+    describe_error: F,
+) -> TokenStream
+where
+    F: Fn(&str, Option<usize>, stylist_core::Error) -> String,
+{
     let mut args_used = HashSet::with_capacity(args.len());
 
-    let output = sheet.to_output_with_args(&args, &mut args_used);
+    let mut contents = Vec::new();
+
+    for part in split_spreads(s) {
+        match part {
+            Part::Css(m) => {
+                let sheet: Sheet = match m.parse() {
+                    Ok(m) => m,
+
+                    Err(e) => {
+                        let offset = e.parse_offset(m);
+                        abort!(first_token, "{}", describe_error(m, offset, e))
+                    }
+                };
+
+                #[cfg(feature = "validation")]
+                if let Some((attr, e)) = sheet.first_invalid_attribute() {
+                    let offset = stylist_core::validation::find_declaration(m, &attr.key);
+                    abort!(first_token, "{}", describe_error(m, offset, e));
+                }
+
+                let output = sheet.to_output_with_args(args, &mut args_used);
+                contents.extend(output.contents);
+            }
+
+            // Paths refer to constants in scope.
+            Part::Spread(m) if m.contains("::") => {
+                let path = match syn::parse_str::<syn::Expr>(m) {
+                    Ok(m) => m,
+                    Err(e) => abort!(first_token, "invalid path `{}`: {}", m, e),
+                };
+
+                contents.push(OutputSheetContent::Spread(path.to_token_stream()));
+            }
+
+            Part::Spread(m) => {
+                let arg = match args.get(m) {
+                    Some(m) => m,
+                    None => abort!(first_token, "missing argument: {}", m),
+                };
+
+                args_used.insert(arg.name.clone());
+                contents.push(OutputSheetContent::Spread(arg.tokens.clone()));
+            }
+        }
+    }
+
+    let output = OutputSheet { contents };
 
     for (k, v) in args.iter() {
         if !args_used.contains(k) {
@@ -132,3 +224,27 @@ pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
     let mut ctx = ReifyContext::new();
     output.into_token_stream(&mut ctx)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_error_after_spread() {
+        let literal = "color: blue;\n...${base};\n.a {\n    color: red;\n";
+        let part = match split_spreads(literal).pop() {
+            Some(Part::Css(m)) => m,
+            m => panic!("unexpected part: {:?}", m),
+        };
+
+        let e = part.parse::<Sheet>().unwrap_err();
+        let offset = e.parse_offset(part);
+
+        let message = describe_error(literal, part, offset, e);
+        assert!(
+            message.ends_with("(at line 3, column 1 of the literal)"),
+            "{}",
+            message
+        );
+    }
+}
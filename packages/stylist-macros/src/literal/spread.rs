@@ -0,0 +1,97 @@
+//! Splits a string literal at spreads of other sources, e.g.: `...${base};`.
+//!
+//! Spreads are only recognised at the top level of the stylesheet, so every part between two
+//! spreads is a stylesheet on its own.
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Part<'a> {
+    Css(&'a str),
+    Spread(&'a str),
+}
+
+pub(crate) fn split_spreads(s: &str) -> Vec<Part<'_>> {
+    let mut parts = Vec::new();
+
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    let mut skip_to = 0;
+
+    for (i, c) in s.char_indices() {
+        if i < skip_to {
+            continue;
+        }
+
+        if let Some(m) = quote {
+            if c == m {
+                quote = None;
+            }
+            continue;
+        }
+
+        match c {
+            '"' | '\'' => quote = Some(c),
+            // Comments are skipped like strings, they may contain quotes or spreads.
+            '/' if s[i..].starts_with("/*") => {
+                skip_to = match s[i + 2..].find("*/") {
+                    Some(m) => i + 2 + m + 2,
+                    None => s.len(),
+                };
+            }
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '.' if depth == 0 && s[i..].starts_with("...${") => {
+                let name_start = i + "...${".len();
+                let name_end = match s[name_start..].find('}') {
+                    Some(m) => name_start + m,
+                    // Left to the stylesheet parser to report.
+                    None => continue,
+                };
+
+                let rest = &s[name_end + 1..];
+                let trimmed = rest.trim_start();
+                let end = s.len() - trimmed.strip_prefix(';').unwrap_or(trimmed).len();
+
+                parts.push(Part::Css(&s[start..i]));
+                parts.push(Part::Spread(s[name_start..name_end].trim()));
+
+                start = end;
+                skip_to = end;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(Part::Css(&s[start..]));
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_spreads() {
+        assert_eq!(
+            split_spreads("...${base}; color: red; .a { content: \"...${b}\"; }"),
+            vec![
+                Part::Css(""),
+                Part::Spread("base"),
+                Part::Css(" color: red; .a { content: \"...${b}\"; }"),
+            ]
+        );
+        assert_eq!(
+            split_spreads("color: ${color};"),
+            vec![Part::Css("color: ${color};")]
+        );
+        assert_eq!(
+            split_spreads("/* don't */ ...${base}; /* ...${b}; */ color: red;"),
+            vec![
+                Part::Css("/* don't */ "),
+                Part::Spread("base"),
+                Part::Css(" /* ...${b}; */ color: red;"),
+            ]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use proc_macro_error::abort_call_site;
+use syn::Expr;
 
 use stylist_core::ast::*;
 
@@ -29,10 +30,18 @@ impl ToOutputWithArgs for Selector {
         args: &HashMap<String, Argument>,
         args_used: &mut HashSet<String>,
     ) -> Self::Output {
-        let mut selectors = Vec::new();
+        let mut selectors: Vec<OutputFragment> = Vec::new();
 
         for frag in self.fragments.iter() {
-            selectors.extend(frag.to_output_with_args(args, args_used));
+            for m in frag.to_output_with_args(args, args_used) {
+                // An argument after `.` or `#` is a part of a class name or an id, e.g.: `.${id}`.
+                let is_name = match selectors.last() {
+                    Some(OutputFragment::Str(ref s)) => s.ends_with(['.', '#']),
+                    _ => false,
+                };
+
+                selectors.push(if is_name { m } else { m.into_selector() });
+            }
         }
         OutputSelector { selectors }
     }
@@ -134,6 +143,16 @@ impl ToOutputWithArgs for StringFragment {
                     fragments_out.push(OutputFragment::Str(m.clone()));
                 }
 
+                // Paths refer to constants in scope, such as theme tokens.
+                fstring::Fragment::Interpolation(ref m) if m.contains("::") => {
+                    let path = match syn::parse_str::<Expr>(m) {
+                        Ok(m) => m,
+                        Err(e) => abort_call_site!("invalid path `{}`: {}", m, e),
+                    };
+
+                    fragments_out.push(path.into());
+                }
+
                 fstring::Fragment::Interpolation(ref m) => {
                     let arg = match args.get(m) {
                         Some(m) => m,
@@ -206,7 +225,7 @@ impl ToOutputWithArgs for Sheet {
         let mut contents = Vec::new();
 
         for i in self.iter() {
-            contents.push(i.to_output_with_args(args, args_used));
+            contents.push(i.to_output_with_args(args, args_used).into());
         }
         OutputSheet { contents }
     }
@@ -29,6 +29,28 @@ impl OutputCowString {
     }
 }
 
+impl OutputCowString {
+    // Like `from_displayable_spanned`, but prints types implementing `ClassSelector` as class
+    // selectors.
+    pub fn from_selector_spanned(source: impl Spanned, expr: impl Reify) -> Self {
+        let mut inner_context = ReifyContext::new();
+        let expr = expr.into_token_stream(&mut inner_context);
+        // Not cached statically, so interpolated styles are collected as dependencies every time
+        // the sheet is created.
+        inner_context.uses_dynamic_argument();
+        Self::Raw(
+            quote_spanned! {source.span()=>
+                {
+                    #[allow(unused_imports)]
+                    use ::stylist::macros::selector::{ViaClassSelector as _, ViaDisplay as _};
+                    (&::stylist::macros::selector::Wrap(&(#expr))).__stylist_selector().into()
+                }
+            },
+            inner_context,
+        )
+    }
+}
+
 impl Reify for OutputCowString {
     fn into_token_stream(self, ctx: &mut ReifyContext) -> TokenStream {
         match self {
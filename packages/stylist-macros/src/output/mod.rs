@@ -24,7 +24,7 @@ pub use rule::OutputRule;
 pub use rule_block_content::OutputRuleBlockContent;
 pub use scope_content::OutputScopeContent;
 pub use selector::OutputSelector;
-pub use sheet::OutputSheet;
+pub use sheet::{OutputSheet, OutputSheetContent};
 pub use str_frag::{fragment_coalesce, OutputFragment};
 pub use style_attr::OutputAttribute;
 
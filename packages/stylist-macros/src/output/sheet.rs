@@ -2,16 +2,30 @@ use super::{IntoCowVecTokens, OutputScopeContent, Reify, ReifyContext};
 use proc_macro2::TokenStream;
 use quote::quote;
 
+#[derive(Debug)]
+pub enum OutputSheetContent {
+    Scope(OutputScopeContent),
+    // An expression of a source that is spliced into the sheet, written as `...${base}`.
+    Spread(TokenStream),
+}
+
+impl From<OutputScopeContent> for OutputSheetContent {
+    fn from(scope: OutputScopeContent) -> Self {
+        Self::Scope(scope)
+    }
+}
+
 #[derive(Debug)]
 pub struct OutputSheet {
-    pub contents: Vec<OutputScopeContent>,
+    pub contents: Vec<OutputSheetContent>,
 }
 
-impl Reify for OutputSheet {
-    fn into_token_stream(self, ctx: &mut ReifyContext) -> TokenStream {
-        let contents = self
-            .contents
-            .into_cow_vec_tokens(quote! {::stylist::ast::ScopeContent}, ctx);
+impl OutputSheet {
+    fn scopes_into_token_stream(
+        contents: Vec<OutputScopeContent>,
+        ctx: &mut ReifyContext,
+    ) -> TokenStream {
+        let contents = contents.into_cow_vec_tokens(quote! {::stylist::ast::ScopeContent}, ctx);
 
         ctx.uses_static(); // Sheet::from
         let quoted_sheet = quote! {
@@ -37,3 +51,49 @@ impl Reify for OutputSheet {
         }
     }
 }
+
+impl Reify for OutputSheet {
+    fn into_token_stream(self, ctx: &mut ReifyContext) -> TokenStream {
+        // Consecutive scopes are reified into their own sheets, so they can still be cached
+        // statically, and extended with the spread sources in order.
+        let mut parts = Vec::new();
+        let mut scopes = Vec::new();
+        let mut has_spread = false;
+
+        let flush_scopes = |scopes: &mut Vec<OutputScopeContent>, parts: &mut Vec<TokenStream>| {
+            if !scopes.is_empty() {
+                let mut inner_ctx = ReifyContext::new();
+                parts.push(Self::scopes_into_token_stream(
+                    std::mem::take(scopes),
+                    &mut inner_ctx,
+                ));
+            }
+        };
+
+        for content in self.contents {
+            match content {
+                OutputSheetContent::Scope(m) => scopes.push(m),
+                OutputSheetContent::Spread(m) => {
+                    flush_scopes(&mut scopes, &mut parts);
+                    parts.push(quote! { ::stylist::macros::spread_source(&(#m)) });
+                    has_spread = true;
+                }
+            }
+        }
+
+        if !has_spread {
+            return Self::scopes_into_token_stream(scopes, ctx);
+        }
+
+        flush_scopes(&mut scopes, &mut parts);
+
+        // Spread sources are parsed when the style is created, so errors are returned instead
+        // of panicking.
+        ctx.uses_dynamic_argument();
+        quote! {
+            ::stylist::StyleSource::from_spread(::std::vec![
+                #( ::stylist::StyleSource::from(#parts), )*
+            ])
+        }
+    }
+}
@@ -10,11 +10,13 @@ use crate::{
 
 #[derive(Debug, Clone)]
 pub enum OutputFragment {
-    Expr(Expr),
+    Expr(Box<Expr>),
     Arg(Argument),
     Token(PreservedToken),
     Delimiter(Delimiter, /*start:*/ bool),
     Str(String),
+    // An interpolation in a selector, see `into_selector`.
+    Selector(Box<OutputFragment>),
 }
 
 impl From<char> for OutputFragment {
@@ -46,7 +48,7 @@ impl From<CssIdent> for OutputFragment {
 
 impl From<Expr> for OutputFragment {
     fn from(expr: Expr) -> Self {
-        Self::Expr(expr)
+        Self::Expr(Box::new(expr))
     }
 }
 
@@ -57,8 +59,26 @@ impl From<Argument> for OutputFragment {
 }
 
 impl OutputFragment {
+    /// Marks an interpolated fragment as part of a selector, so classes of styles are printed
+    /// as class selectors.
+    pub fn into_selector(self) -> Self {
+        match self {
+            Self::Expr(_) | Self::Arg(_) => Self::Selector(Box::new(self)),
+            m => m,
+        }
+    }
+
     pub fn into_inner(self) -> OutputCowString {
         match self {
+            Self::Selector(m) => match *m {
+                Self::Arg(arg) => {
+                    OutputCowString::from_selector_spanned(arg.name_token, arg.tokens)
+                }
+                Self::Expr(expr) if !matches!(*expr, Expr::Lit(_)) => {
+                    OutputCowString::from_selector_spanned(expr.span(), *expr)
+                }
+                m => m.into_inner(),
+            },
             Self::Token(t) => t.to_output_string().into(),
             Self::Delimiter(kind, start) => Self::str_for_delim(kind, start).to_string().into(),
             Self::Str(s) => s.into(),
@@ -67,11 +87,11 @@ impl OutputFragment {
                 if let Expr::Lit(ExprLit {
                     lit: Lit::Str(ref litstr),
                     ..
-                }) = expr
+                }) = *expr
                 {
                     litstr.value().into()
                 } else {
-                    OutputCowString::from_displayable_spanned(expr.span(), expr)
+                    OutputCowString::from_displayable_spanned(expr.span(), *expr)
                 }
             }
         }
@@ -1,4 +1,5 @@
 use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
 
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
     if let Some(TokenTree::Literal(_)) = input.clone().into_iter().next() {
@@ -7,3 +8,11 @@ pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
         crate::inline::macro_fn(input)
     }
 }
+
+/// `sheet!`, which returns a sheet even if the stylesheet has spreads.
+pub(crate) fn sheet_macro_fn(input: TokenStream) -> TokenStream {
+    let sheet_tokens = macro_fn(input);
+
+    // Stylesheets with spreads are reified into a source.
+    quote! { ::stylist::macros::IntoSheet::into_sheet(#sheet_tokens) }
+}
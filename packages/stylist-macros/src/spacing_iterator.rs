@@ -1,5 +1,40 @@
 //! Provide an iterator inserting optional items between items
 
+use proc_macro2::Span;
+
+/// Returns whether there is whitespace between two tokens in the macro input.
+///
+/// Returns `None` if the location of either token is unknown, e.g.: one of the tokens was created
+/// by another macro. Locations of macro input are exposed since Rust 1.88, which is the minimum
+/// supported Rust version of this crate.
+pub fn source_spacing(left: Span, right: Span) -> Option<bool> {
+    let end = left.end();
+    let start = right.start();
+
+    // Lines are 1-indexed, line 0 denotes an unknown location.
+    if end.line == 0 || start.line == 0 || (start.line, start.column) < (end.line, end.column) {
+        return None;
+    }
+
+    Some((start.line, start.column) != (end.line, end.column))
+}
+
+#[test]
+fn test_source_spacing() {
+    use proc_macro2::{TokenStream, TokenTree};
+
+    let spans: Vec<Span> = "a.b c"
+        .parse::<TokenStream>()
+        .unwrap()
+        .into_iter()
+        .map(|m: TokenTree| m.span())
+        .collect();
+
+    assert_eq!(source_spacing(spans[0], spans[1]), Some(false));
+    assert_eq!(source_spacing(spans[2], spans[3]), Some(true));
+    assert_eq!(source_spacing(spans[3], spans[0]), None);
+}
+
 #[test]
 fn test_spacing_iterator() {
     use SpacedIterator;
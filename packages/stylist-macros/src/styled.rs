@@ -0,0 +1,185 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, parse_quote, token, Attribute, Field, Ident, ItemFn, Token, Visibility};
+
+use super::styled_component_impl::{styled_component_impl_impl, yew_manager, HookLike};
+
+/// `styled!(pub Button { primary: bool }: button { color: red; })`
+#[derive(Debug)]
+pub struct Styled {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    fields: Punctuated<Field, Token![,]>,
+    tag: Ident,
+    css: TokenStream,
+}
+
+impl Parse for Styled {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+
+        let fields = if input.peek(token::Brace) {
+            let inner;
+            braced!(inner in input);
+            inner.parse_terminated(Field::parse_named)?
+        } else {
+            Punctuated::new()
+        };
+
+        input.parse::<Token![:]>()?;
+        let tag = input.parse()?;
+
+        let inner;
+        braced!(inner in input);
+        let css = inner.parse()?;
+
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the styles of the component"));
+        }
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            fields,
+            tag,
+            css,
+        })
+    }
+}
+
+pub fn styled_impl(styled: Styled) -> syn::Result<TokenStream> {
+    let Styled {
+        attrs,
+        vis,
+        name,
+        fields,
+        tag,
+        css,
+    } = styled;
+
+    let props_ident = format_ident!("{}Props", name);
+    let fn_ident = format_ident!("__stylist_styled_{}", name);
+    let class_ident = Ident::new("__stylist_class__", Span::mixed_site());
+
+    // Fields marked with `#[attr]` are forwarded to the element as attributes or listeners.
+    let mut forwarded = Vec::new();
+    let fields = fields
+        .into_iter()
+        .map(|mut m| {
+            let len = m.attrs.len();
+            m.attrs.retain(|m| !m.path.is_ident("attr"));
+
+            if m.attrs.len() != len {
+                let field = m.ident.clone().expect("fields are named");
+                // Raw identifiers such as `r#type` are forwarded as `type`.
+                let name = Ident::new(&field.unraw().to_string(), field.span());
+                forwarded.push(quote! { #name={props.#field.clone()} });
+            }
+
+            m.vis = parse_quote! { pub };
+            m
+        })
+        .collect::<Vec<_>>();
+
+    let func: ItemFn = parse_quote! {
+        #(#attrs)*
+        #[allow(non_snake_case)]
+        #vis fn #fn_ident(props: &#props_ident) -> ::yew::html::Html {
+            let #class_ident = css!(#css);
+
+            ::yew::html! {
+                <#tag
+                    class={::yew::classes!(#class_ident, props.class.clone())}
+                    id={props.id.clone()}
+                    style={props.style.clone()}
+                    title={props.title.clone()}
+                    onclick={props.onclick.clone()}
+                    onkeydown={props.onkeydown.clone()}
+                    onfocus={props.onfocus.clone()}
+                    onblur={props.onblur.clone()}
+                    #(#forwarded)*
+                >
+                    { props.children.clone() }
+                </#tag>
+            }
+        }
+    };
+
+    let inner_tokens = styled_component_impl_impl(HookLike { func }, yew_manager())?;
+    let props_doc = format!("The properties of [`{}`].", name);
+
+    Ok(quote! {
+        #[doc = #props_doc]
+        #[derive(::yew::Properties, ::std::clone::Clone, ::std::cmp::PartialEq)]
+        #vis struct #props_ident {
+            #(#fields,)*
+            #[prop_or_default]
+            pub children: ::yew::html::Children,
+            #[prop_or_default]
+            pub class: ::yew::html::Classes,
+            #[prop_or_default]
+            pub id: ::std::option::Option<::std::string::String>,
+            #[prop_or_default]
+            pub style: ::std::option::Option<::std::string::String>,
+            #[prop_or_default]
+            pub title: ::std::option::Option<::std::string::String>,
+            #[prop_or_default]
+            pub onclick: ::std::option::Option<::yew::callback::Callback<::yew::events::MouseEvent>>,
+            #[prop_or_default]
+            pub onkeydown: ::std::option::Option<::yew::callback::Callback<::yew::events::KeyboardEvent>>,
+            #[prop_or_default]
+            pub onfocus: ::std::option::Option<::yew::callback::Callback<::yew::events::FocusEvent>>,
+            #[prop_or_default]
+            pub onblur: ::std::option::Option<::yew::callback::Callback<::yew::events::FocusEvent>>,
+        }
+
+        #[::yew::functional::function_component(#name)]
+        #inner_tokens
+    })
+}
+
+pub fn macro_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let styled = syn::parse_macro_input!(input as Styled);
+
+    styled_impl(styled)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let styled: Styled = syn::parse2(quote! {
+            pub Button {
+                #[prop_or_default] primary: bool,
+                #[attr] #[prop_or_default] r#type: Option<String>,
+            }: button {
+                color: red;
+            }
+        })
+        .expect("Failed to parse styled component.");
+
+        assert_eq!(styled.name, "Button");
+        assert_eq!(styled.tag, "button");
+        assert_eq!(styled.fields.len(), 2);
+
+        let output = styled_impl(styled)
+            .expect("Failed to create styled component.")
+            .to_string();
+        assert!(output.contains("pub struct ButtonProps"));
+        assert!(output.contains("onkeydown = { props . onkeydown . clone () }"));
+        assert!(output.contains("type = { props . r#type . clone () }"));
+        assert!(!output.contains("primary = {"));
+        assert!(!output.contains("# [attr]"));
+    }
+}
@@ -5,7 +5,7 @@ use syn::parse::{Parse, ParseStream};
 use syn::parse_macro_input;
 use syn::{Item, ItemFn};
 
-use super::styled_component_impl::{styled_component_impl_impl, HookLike};
+use super::styled_component_impl::{styled_component_impl_impl, yew_manager, HookLike};
 
 #[derive(Debug)]
 pub struct StyledComponentName {
@@ -48,7 +48,7 @@ pub fn styled_component_impl(
     let StyledComponentName { component_name } = name;
     let StyledComponent { func } = component;
 
-    let inner_tokens = styled_component_impl_impl(HookLike { func })?;
+    let inner_tokens = styled_component_impl_impl(HookLike { func }, yew_manager())?;
 
     Ok(quote! {
         #[::yew::functional::function_component(#component_name)]
@@ -34,7 +34,32 @@ impl Parse for StyledComponentBaseArgs {
     }
 }
 
-pub fn styled_component_impl_impl(item: HookLike) -> syn::Result<TokenStream> {
+/// The expression that retrieves the contextual style manager in yew.
+pub fn yew_manager() -> TokenStream {
+    quote! {
+        ::yew::functional::use_context::<::stylist::manager::StyleManager>().unwrap_or_default()
+    }
+}
+
+/// The expression that retrieves the contextual style manager in leptos.
+pub fn leptos_manager() -> TokenStream {
+    quote! { ::stylist::leptos::use_style_manager() }
+}
+
+/// The expression that retrieves the contextual style manager in dioxus.
+pub fn dioxus_manager() -> TokenStream {
+    quote! { ::stylist::dioxus::use_style_manager() }
+}
+
+/// The expression that retrieves the contextual style manager in sycamore.
+pub fn sycamore_manager() -> TokenStream {
+    quote! { ::stylist::sycamore::use_style_manager() }
+}
+
+pub fn styled_component_impl_impl(
+    item: HookLike,
+    manager: TokenStream,
+) -> syn::Result<TokenStream> {
     let HookLike { func } = item;
 
     let ItemFn {
@@ -59,7 +84,7 @@ pub fn styled_component_impl_impl(item: HookLike) -> syn::Result<TokenStream> {
     let quoted = quote! {
         #(#attrs)*
         #vis #sig {
-            let #mgr_ident = ::yew::functional::use_context::<::stylist::manager::StyleManager>().unwrap_or_default();
+            let #mgr_ident = #manager;
             #macro_tokens
 
             #block
@@ -72,11 +97,12 @@ pub fn styled_component_impl_impl(item: HookLike) -> syn::Result<TokenStream> {
 pub fn macro_fn(
     attr: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
+    manager: TokenStream,
 ) -> proc_macro::TokenStream {
     let item = parse_macro_input!(item as HookLike);
     let _ = parse_macro_input!(attr as StyledComponentBaseArgs);
 
-    styled_component_impl_impl(item)
+    styled_component_impl_impl(item, manager)
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
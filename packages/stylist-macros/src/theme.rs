@@ -0,0 +1,62 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::format_ident;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+pub(crate) fn macro_fn(input: DeriveInput) -> TokenStream {
+    let fields = match input.data {
+        Data::Struct(ref m) => match m.fields {
+            Fields::Named(ref m) => &m.named,
+            _ => abort!(
+                input.ident,
+                "Theme can only be derived for structs with named fields"
+            ),
+        },
+        _ => abort!(input.ident, "Theme can only be derived for structs"),
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut consts = Vec::new();
+    let mut tokens = Vec::new();
+
+    for field in fields.iter() {
+        let field_ident = field
+            .ident
+            .as_ref()
+            .expect("named fields have an identifier");
+        let field_name = field_ident.to_string();
+        let field_name = field_name.trim_start_matches("r#");
+
+        let name = LitStr::new(&field_name.replace('_', "-"), field_ident.span());
+        // Constants are upper cased so they do not collide with methods named after fields.
+        let const_ident = format_ident!("{}", field_name.to_uppercase(), span = field_ident.span());
+
+        consts.push(quote! {
+            pub const #const_ident: ::stylist::ThemeToken = ::stylist::ThemeToken::new(#name);
+        });
+
+        tokens.push(quote! {
+            (
+                Self::#const_ident,
+                ::std::borrow::Cow::Owned(::std::string::ToString::to_string(&self.#field_ident)),
+            )
+        });
+    }
+
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#consts)*
+        }
+
+        impl #impl_generics ::stylist::Theme for #ident #ty_generics #where_clause {
+            fn tokens(
+                &self,
+            ) -> ::std::vec::Vec<(::stylist::ThemeToken, ::std::borrow::Cow<'static, str>)> {
+                ::std::vec![#(#tokens),*]
+            }
+        }
+    }
+}
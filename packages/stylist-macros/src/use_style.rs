@@ -1,6 +1,48 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
 use quote::quote;
 
+/// Splits `(deps) => css` into the dependencies and the stylesheet.
+fn split_deps(input: TokenStream) -> (Option<TokenStream>, TokenStream) {
+    let mut tokens = input.clone().into_iter();
+
+    let deps = match tokens.next() {
+        Some(TokenTree::Group(m)) if m.delimiter() == Delimiter::Parenthesis => m,
+        _ => return (None, input),
+    };
+
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Punct(eq)), Some(TokenTree::Punct(gt)))
+            if eq.as_char() == '=' && gt.as_char() == '>' =>
+        {
+            (Some(TokenTree::Group(deps).into()), tokens.collect())
+        }
+        _ => (None, input),
+    }
+}
+
 pub(crate) fn macro_fn(input: TokenStream) -> TokenStream {
-    quote! { ::stylist::yew::use_style(::stylist::css!(#input)) }
+    match split_deps(input) {
+        (Some(deps), css) => quote! {
+            ::stylist::yew::use_style_with_deps(|_| ::stylist::css!(#css), #deps)
+        },
+        (None, css) => quote! { ::stylist::yew::use_style(::stylist::css!(#css)) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_deps() {
+        let (deps, css) = split_deps(quote! { (props.color.clone(),) => color: red; });
+        assert_eq!(
+            deps.map(|m| m.to_string()),
+            Some(quote! { (props.color.clone(),) }.to_string())
+        );
+        assert_eq!(css.to_string(), quote! { color: red; }.to_string());
+
+        let (deps, _) = split_deps(quote! { "color: red;" });
+        assert!(deps.is_none());
+    }
 }
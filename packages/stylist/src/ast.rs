@@ -17,6 +17,9 @@
 //!         └── Vec<enum RuleBlockContent (*)>
 //! ```
 //!
+//! The [`Display`](std::fmt::Display) implementations of these types print the authored CSS
+//! without applying any scoping, while [`ToStyleStr`] prints the CSS that is mounted.
+//!
 //! # Warning
 //!
 //! This module is not stable at the moment and is exposed to be used by procedural macros.
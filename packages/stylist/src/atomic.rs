@@ -0,0 +1,343 @@
+//! Splits stylesheets into atomic stylesheets with a single declaration each.
+
+use std::borrow::Cow;
+
+use crate::ast::{
+    Block, Rule, RuleBlockContent, ScopeContent, Selector, Sheet, StringFragment, StyleAttribute,
+};
+use crate::priority::repeat_current_selector;
+
+/// Shorthand properties, which set the longhand properties prefixed with their name and the
+/// properties listed in [`longhands_of`].
+const SHORTHANDS: &[&str] = &[
+    "animation",
+    "background",
+    "border",
+    "border-block",
+    "border-bottom",
+    "border-color",
+    "border-image",
+    "border-inline",
+    "border-left",
+    "border-radius",
+    "border-right",
+    "border-style",
+    "border-top",
+    "border-width",
+    "column-rule",
+    "columns",
+    "flex",
+    "flex-flow",
+    "font",
+    "gap",
+    "grid",
+    "grid-area",
+    "grid-column",
+    "grid-row",
+    "grid-template",
+    "inset",
+    "list-style",
+    "margin",
+    "margin-block",
+    "margin-inline",
+    "mask",
+    "outline",
+    "overflow",
+    "padding",
+    "padding-block",
+    "padding-inline",
+    "place-content",
+    "place-items",
+    "place-self",
+    "text-decoration",
+    "transition",
+];
+
+/// Longhand properties of shorthands that are not prefixed with the name of the shorthand.
+fn longhands_of(shorthand: &str) -> &'static [&'static str] {
+    match shorthand {
+        "border-color" => &[
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+        "border-style" => &[
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+        "border-width" => &[
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        "flex-flow" => &["flex-direction", "flex-wrap"],
+        "gap" => &["row-gap", "column-gap"],
+        "inset" => &["top", "right", "bottom", "left"],
+        "place-content" => &["align-content", "justify-content"],
+        "place-items" => &["align-items", "justify-items"],
+        "place-self" => &["align-self", "justify-self"],
+        _ => &[],
+    }
+}
+
+/// Returns if `shorthand` sets `property`.
+fn is_longhand_of(property: &str, shorthand: &str) -> bool {
+    let is_prefixed = property
+        .strip_prefix(shorthand)
+        .map(|m| m.starts_with('-'))
+        .unwrap_or(false);
+
+    is_prefixed || longhands_of(shorthand).contains(&property)
+}
+
+/// The maximum number of shorthands that set a property, e.g.: `border-top-color` is set by
+/// `border`, `border-top` and `border-color`.
+const MAX_SHORTHANDS: usize = 3;
+
+/// Returns the number of shorthands that set `property`.
+///
+/// Longhands are ranked above their shorthands, so a longhand overrides its shorthand no matter
+/// in which order atomic styles are mounted.
+fn shorthand_rank(property: &str) -> usize {
+    let property = property.to_ascii_lowercase();
+
+    SHORTHANDS
+        .iter()
+        .filter(|m| is_longhand_of(&property, m))
+        .count()
+        .min(MAX_SHORTHANDS)
+}
+
+/// The context of a declaration.
+#[derive(Debug, Clone, Default)]
+struct Context {
+    // The conditions of the at-rules the declaration is in, from the outermost at-rule.
+    rules: Vec<Cow<'static, [StringFragment]>>,
+    selectors: Cow<'static, [Selector]>,
+}
+
+#[derive(Debug)]
+enum Atom {
+    Attr(Context, StyleAttribute),
+    // A rule that cannot be split, like keyframes.
+    Rule(Context, Rule),
+}
+
+impl Atom {
+    /// Returns if `other` is the same declaration or a longhand of this declaration in the same
+    /// context, so only this declaration applies if it comes after `other`.
+    fn overrides(&self, other: &Atom) -> bool {
+        match (self, other) {
+            (Self::Attr(ref ctx, ref attr), Self::Attr(ref other_ctx, ref other_attr)) => {
+                let key = attr.key.to_ascii_lowercase();
+                let other_key = other_attr.key.to_ascii_lowercase();
+
+                (key == other_key || is_longhand_of(&other_key, &key))
+                    && ctx.rules == other_ctx.rules
+                    && ctx.selectors == other_ctx.selectors
+            }
+            _ => false,
+        }
+    }
+
+    fn into_sheet(self, priority: usize) -> Sheet {
+        let (ctx, content) = match self {
+            Self::Attr(ctx, attr) => {
+                // The class selector is repeated for each selector and at-rule of the context,
+                // and within the same context for each shorthand that sets the declaration, so
+                // declarations in a more specific context and longhands take precedence no
+                // matter in which order atomic styles are mounted.
+                let context_rank = ctx.rules.len() + (!ctx.selectors.is_empty()) as usize;
+                let times =
+                    1 + priority + context_rank * (MAX_SHORTHANDS + 1) + shorthand_rank(&attr.key);
+                let selectors = if ctx.selectors.is_empty() {
+                    vec![Selector::from(vec!["&".repeat(times).into()])]
+                } else {
+                    ctx.selectors
+                        .iter()
+                        .map(|m| Selector::from(vec![repeat_current_selector(m, times).into()]))
+                        .collect()
+                };
+
+                let block = Block {
+                    condition: selectors.into(),
+                    content: vec![RuleBlockContent::StyleAttr(attr)].into(),
+                };
+
+                (ctx, RuleBlockContent::Block(block.into()))
+            }
+            Self::Rule(ctx, rule) => (ctx, RuleBlockContent::Rule(rule.into())),
+        };
+
+        let content = ctx
+            .rules
+            .into_iter()
+            .rev()
+            .fold(content, |content, condition| {
+                RuleBlockContent::Rule(
+                    Rule {
+                        condition,
+                        content: vec![content].into(),
+                    }
+                    .into(),
+                )
+            });
+
+        let scope = match content {
+            RuleBlockContent::Rule(m) => ScopeContent::Rule((*m).clone()),
+            RuleBlockContent::Block(m) => ScopeContent::Block((*m).clone()),
+            RuleBlockContent::StyleAttr(_) => unreachable!(),
+        };
+
+        Sheet::from(vec![scope])
+    }
+}
+
+fn is_keyframes(rule: &Rule) -> bool {
+    let condition = rule
+        .condition
+        .first()
+        .map(|m| m.inner.as_ref())
+        .unwrap_or("");
+
+    condition.starts_with("@keyframes") || condition.starts_with("@-webkit-keyframes")
+}
+
+fn collect(content: &RuleBlockContent, ctx: &mut Context, atoms: &mut Vec<Atom>) {
+    match content {
+        RuleBlockContent::StyleAttr(ref m) => atoms.push(Atom::Attr(ctx.clone(), m.clone())),
+        RuleBlockContent::Block(ref m) => collect_block(m, ctx, atoms),
+        RuleBlockContent::Rule(ref m) => collect_rule(m, ctx, atoms),
+    }
+}
+
+fn collect_block(block: &Block, ctx: &mut Context, atoms: &mut Vec<Atom>) {
+    if block.condition.is_empty() {
+        for content in block.content.iter() {
+            collect(content, ctx, atoms);
+        }
+        return;
+    }
+
+    let selectors = std::mem::replace(&mut ctx.selectors, block.condition.clone());
+    for content in block.content.iter() {
+        collect(content, ctx, atoms);
+    }
+    ctx.selectors = selectors;
+}
+
+fn collect_rule(rule: &Rule, ctx: &mut Context, atoms: &mut Vec<Atom>) {
+    if is_keyframes(rule) {
+        atoms.push(Atom::Rule(
+            Context {
+                rules: ctx.rules.clone(),
+                selectors: Cow::Borrowed(&[]),
+            },
+            rule.clone(),
+        ));
+        return;
+    }
+
+    ctx.rules.push(rule.condition.clone());
+    for content in rule.content.iter() {
+        collect(content, ctx, atoms);
+    }
+    ctx.rules.pop();
+}
+
+/// Splits `sheet` into stylesheets with a single declaration each, in order of appearance.
+///
+/// If a declaration is repeated or a shorthand follows its longhands in the same context, only
+/// the last declaration is kept, as it is the one that applies. The specificity of each
+/// declaration is raised by `priority` classes.
+pub(crate) fn atomize(sheet: &Sheet, priority: usize) -> Vec<Sheet> {
+    let mut atoms = Vec::new();
+    let mut ctx = Context::default();
+
+    for scope in sheet.iter() {
+        match scope {
+            ScopeContent::Block(ref m) => collect_block(m, &mut ctx, &mut atoms),
+            ScopeContent::Rule(ref m) => collect_rule(m, &mut ctx, &mut atoms),
+        }
+    }
+
+    let mut kept: Vec<Atom> = Vec::with_capacity(atoms.len());
+    for atom in atoms {
+        kept.retain(|m| !atom.overrides(m));
+        kept.push(atom);
+    }
+
+    kept.into_iter().map(|m| m.into_sheet(priority)).collect()
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[test]
+    fn test_atomize() {
+        let sheet: Sheet = r#"
+            color: red;
+            color: blue;
+            &:hover, .child {
+                color: green;
+            }
+            @media print {
+                display: none;
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        let atoms = atomize(&sheet, 0)
+            .iter()
+            .map(|m| m.to_style_str(Some("a")))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            atoms,
+            vec![
+                ".a {\n    color: blue;\n}\n",
+                ".a.a.a.a.a:hover, .a.a.a.a.a .child {\n    color: green;\n}\n",
+                "@media print {\n    .a.a.a.a.a {\n        display: none;\n    }\n}\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_atomize_shorthands() {
+        let atomize_str = |s: &str| {
+            let sheet: Sheet = s.parse().expect("Failed to parse sheet.");
+
+            atomize(&sheet, 0)
+                .iter()
+                .map(|m| m.to_style_str(Some("a")))
+                .collect::<Vec<_>>()
+        };
+
+        // The longhand is ranked above the shorthand, regardless of the mount order.
+        assert_eq!(
+            atomize_str("padding: 4px; padding-left: 0;"),
+            vec![
+                ".a {\n    padding: 4px;\n}\n",
+                ".a.a {\n    padding-left: 0;\n}\n",
+            ]
+        );
+
+        // A shorthand after its longhand overrides it.
+        assert_eq!(
+            atomize_str("padding-left: 0; padding: 4px;"),
+            vec![".a {\n    padding: 4px;\n}\n"]
+        );
+
+        assert_eq!(shorthand_rank("color"), 0);
+        assert_eq!(shorthand_rank("border-top"), 1);
+        assert_eq!(shorthand_rank("border-top-color"), 3);
+        assert_eq!(shorthand_rank("row-gap"), 1);
+    }
+}
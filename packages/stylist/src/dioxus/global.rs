@@ -0,0 +1,73 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use super::use_style_manager;
+use crate::manager::StyleManager;
+use crate::{GlobalStyle, StyleSource};
+use stylist_core::ResultDisplay;
+
+#[derive(Debug)]
+struct GlobalState {
+    manager: StyleManager,
+    css: StyleSource<'static>,
+    style: GlobalStyle,
+}
+
+/// A Global Style that will be applied to `<html />` tag, inspired by [emotion](https://emotion.sh).
+///
+/// The `css` attribute accepts any type that implements [`Into<StyleSource>`] and panics if the
+/// string failed to be parsed into a stylesheet. The style is created again when `css` changes
+/// and unregistered when the component is removed.
+///
+/// # Example:
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use stylist::dioxus::Global;
+///
+/// #[component]
+/// fn App() -> Element {
+///     rsx! {
+///         Global { css: "color: red;" }
+///         div { "Hello World!" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Global(
+    /// The global stylesheet.
+    #[props(into)]
+    css: StyleSource<'static>,
+) -> Element {
+    let manager = use_style_manager();
+    let state = use_hook(|| Rc::new(RefCell::new(None::<GlobalState>)));
+
+    {
+        let mut state = state.borrow_mut();
+        let changed = state.as_ref().map(|m| m.manager != manager || m.css != css) != Some(false);
+
+        if changed {
+            if let Some(m) = state.take() {
+                m.style.unregister();
+            }
+
+            let style = GlobalStyle::new_with_manager(css.clone(), manager.clone())
+                .expect_display("Failed to create style.");
+            *state = Some(GlobalState {
+                manager,
+                css,
+                style,
+            });
+        }
+    }
+
+    use_drop(move || {
+        if let Some(m) = state.borrow_mut().take() {
+            m.style.unregister();
+        }
+    });
+
+    VNode::empty()
+}
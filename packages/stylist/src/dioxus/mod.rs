@@ -0,0 +1,55 @@
+//! This module contains dioxus specific features.
+
+use dioxus::dioxus_core::{AttributeValue, IntoAttributeValue};
+use dioxus::prelude::try_use_context;
+
+/// A procedural macro to use a specialized, contextual [`css!`](crate::css) macro.
+///
+/// The [`css!`](crate::css) macro in the body of the function uses the style manager provided by
+/// [`ManagerProvider`], or the default style manager if there is none. Apply this attribute
+/// before the [`component`](::dioxus::prelude::component) attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use stylist::dioxus::styled_component_impl;
+///
+/// #[styled_component_impl]
+/// #[component]
+/// fn MyStyledComponent() -> Element {
+///     rsx! { div { class: css!("color: red;"), "Hello World!" } }
+/// }
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::dioxus_styled_component_impl as styled_component_impl;
+
+use crate::manager::StyleManager;
+use crate::{Style, StyleSource};
+
+mod global;
+mod provider;
+
+pub use global::{Global, GlobalProps};
+pub use provider::{ManagerProvider, ManagerProviderProps};
+
+/// Returns the style manager provided by [`ManagerProvider`], or the default style manager if
+/// there is none.
+pub fn use_style_manager() -> StyleManager {
+    try_use_context::<StyleManager>().unwrap_or_default()
+}
+
+/// Uses the class name of the [`Style`] as the value of an attribute, like `class`.
+impl IntoAttributeValue for Style {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.get_class_name().to_string())
+    }
+}
+
+/// Creates a [`Style`] and uses its class name as the value of an attribute, like `class`.
+impl IntoAttributeValue for StyleSource<'_> {
+    fn into_value(self) -> AttributeValue {
+        AttributeValue::Text(self.to_style().get_class_name().to_string())
+    }
+}
@@ -0,0 +1,35 @@
+use dioxus::prelude::*;
+
+use crate::manager::StyleManager;
+
+/// A Context Provider to provide a custom [`StyleManager`] to child components.
+///
+/// The manager is provided when the component is created, later changes of the `manager`
+/// property are not propagated to child components.
+///
+/// # Example:
+///
+/// ```
+/// use dioxus::prelude::*;
+/// use stylist::dioxus::ManagerProvider;
+/// use stylist::manager::StyleManager;
+///
+/// #[component]
+/// fn App(children: Element) -> Element {
+///     let mgr = use_hook(|| StyleManager::builder().prefix("my-styles".into()).build().unwrap());
+///
+///     rsx! {
+///         ManagerProvider { manager: mgr, {children} }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ManagerProvider(
+    /// The style manager provided to child components.
+    manager: StyleManager,
+    children: Element,
+) -> Element {
+    use_context_provider(|| manager);
+
+    children
+}
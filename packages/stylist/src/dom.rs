@@ -0,0 +1,246 @@
+//! Framework-agnostic helpers to apply styles to DOM elements.
+//!
+//! Without a framework integration, the lifetime of the classes of an element and of global
+//! styles can be tied to guards:
+//!
+//! ```no_run
+//! use stylist::{GlobalStyle, Style};
+//!
+//! let window = web_sys::window().unwrap();
+//! let element = window.document().unwrap().create_element("div").unwrap();
+//!
+//! let global_guard = GlobalStyle::new("body { margin: 0; }")?.into_guard();
+//!
+//! let style = Style::new("color: red;")?;
+//! let guard = style.apply_to(&element)?;
+//!
+//! // Removes the class of the style from the element and unregisters the style.
+//! drop(guard);
+//! // Unregisters the global style.
+//! drop(global_guard);
+//! # Ok::<(), stylist::Error>(())
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use web_sys::Element;
+
+use crate::{Error, GlobalStyle, Result, Style};
+
+/// Counts the live guards of each class of each element.
+#[derive(Debug)]
+struct AppliedClasses<E> {
+    /// The number of guards that applied each class to an element.
+    elements: Vec<(E, HashMap<String, usize>)>,
+}
+
+impl<E> Default for AppliedClasses<E> {
+    fn default() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+}
+
+impl<E: PartialEq + Clone> AppliedClasses<E> {
+    /// Counts a guard of the style with `class_name` on `element`.
+    fn add(&mut self, element: &E, class_name: &str) {
+        let classes = match self.elements.iter().position(|(m, _)| m == element) {
+            Some(index) => &mut self.elements[index].1,
+            None => {
+                self.elements.push((element.clone(), HashMap::new()));
+                &mut self.elements.last_mut().expect("just pushed").1
+            }
+        };
+
+        for class_name in class_name.split_whitespace() {
+            *classes.entry(class_name.to_string()).or_default() += 1;
+        }
+    }
+
+    /// Uncounts a guard of the style with `class_name` on `element`.
+    ///
+    /// Returns the classes that are no longer applied to `element` by any guard.
+    fn remove(&mut self, element: &E, class_name: &str) -> Vec<String> {
+        let Some(index) = self.elements.iter().position(|(m, _)| m == element) else {
+            return Vec::new();
+        };
+
+        let classes = &mut self.elements[index].1;
+        let mut removed = Vec::new();
+        for class_name in class_name.split_whitespace() {
+            match classes.get_mut(class_name) {
+                Some(count) if *count > 1 => *count -= 1,
+                _ => {
+                    classes.remove(class_name);
+                    removed.push(class_name.to_string());
+                }
+            }
+        }
+
+        if classes.is_empty() {
+            self.elements.swap_remove(index);
+        }
+
+        removed
+    }
+}
+
+thread_local! {
+    static APPLIED: RefCell<AppliedClasses<Element>> = RefCell::default();
+}
+
+/// A guard that removes the class of a [`Style`] from an element when it is dropped.
+///
+/// Created by [`Style::apply_to`].
+#[derive(Debug)]
+#[must_use = "the class is removed from the element when the guard is dropped"]
+pub struct StyleGuard {
+    style: Style,
+    element: Element,
+}
+
+impl StyleGuard {
+    /// The style applied to the element.
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+
+    /// The element the style is applied to.
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+}
+
+impl Drop for StyleGuard {
+    fn drop(&mut self) {
+        let removed = APPLIED.with(|m| {
+            m.borrow_mut()
+                .remove(&self.element, self.style.get_class_name())
+        });
+
+        let class_list = self.element.class_list();
+        for class_name in removed {
+            // The element may have been modified by others, which is not an error.
+            let _ = class_list.remove_1(&class_name);
+        }
+
+        self.style.release();
+    }
+}
+
+impl Style {
+    /// Adds the class of this style to `element` and returns a guard that removes the class when
+    /// it is dropped.
+    ///
+    /// A class is only removed from the element after all guards that applied it to the element
+    /// are dropped, so styles applied multiple times, or atomic styles sharing classes, stay
+    /// applied while any of their guards is alive.
+    ///
+    /// The style is unregistered after all of its guards are dropped. Like other styles, it is
+    /// unmounted once all other references to it are dropped as well.
+    pub fn apply_to(&self, element: &Element) -> Result<StyleGuard> {
+        let class_list = element.class_list();
+
+        // Atomic styles have a class for each declaration.
+        for class_name in self.get_class_name().split_whitespace() {
+            class_list
+                .add_1(class_name)
+                .map_err(|e| Error::Web(Some(e)))?;
+        }
+
+        APPLIED.with(|m| m.borrow_mut().add(element, self.get_class_name()));
+        self.acquire();
+
+        Ok(StyleGuard {
+            style: self.clone(),
+            element: element.clone(),
+        })
+    }
+}
+
+/// A guard that unregisters a [`GlobalStyle`] when it is dropped.
+///
+/// Created by [`GlobalStyle::into_guard`].
+#[derive(Debug)]
+#[must_use = "the global style is unregistered when the guard is dropped"]
+pub struct GlobalStyleGuard {
+    style: GlobalStyle,
+}
+
+impl GlobalStyleGuard {
+    /// The guarded global style.
+    pub fn style(&self) -> &GlobalStyle {
+        &self.style
+    }
+}
+
+impl Drop for GlobalStyleGuard {
+    fn drop(&mut self) {
+        self.style.unregister();
+    }
+}
+
+impl GlobalStyle {
+    /// Ties the lifetime of this global style to a guard, which unregisters the style when it is
+    /// dropped.
+    ///
+    /// The style is unmounted after the guard and all other references to it are dropped.
+    pub fn into_guard(self) -> GlobalStyleGuard {
+        GlobalStyleGuard { style: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manager::StyleManager;
+
+    #[test]
+    fn test_applied_classes() {
+        let mut applied = AppliedClasses::default();
+
+        // Atomic styles sharing the class `a` on the same element.
+        applied.add(&1, "a b");
+        applied.add(&1, "a c");
+        applied.add(&2, "a b");
+
+        assert_eq!(applied.remove(&1, "a b"), vec!["b".to_string()]);
+        assert_eq!(applied.remove(&1, "a c"), vec!["a".to_string(), "c".into()]);
+        assert_eq!(applied.remove(&2, "a b"), vec!["a".to_string(), "b".into()]);
+        assert!(applied.elements.is_empty());
+
+        // The same style applied twice to an element.
+        applied.add(&1, "a");
+        applied.add(&1, "a");
+
+        assert!(applied.remove(&1, "a").is_empty());
+        assert_eq!(applied.remove(&1, "a"), vec!["a".to_string()]);
+        assert!(applied.elements.is_empty());
+    }
+
+    #[test]
+    fn test_global_style_guard() {
+        let manager = StyleManager::builder()
+            .prefix("test-guard".into())
+            .build()
+            .expect("Failed to create manager.");
+
+        let guard = GlobalStyle::new_with_manager("color: red;", &manager)
+            .expect("Failed to create style.")
+            .into_guard();
+        let id = guard.style().id().to_string();
+
+        // Registered styles are reused while the guard is alive.
+        let style = GlobalStyle::new_with_manager("color: red;", &manager)
+            .expect("Failed to create style.");
+        assert_eq!(style.id().to_string(), id);
+
+        drop(guard);
+
+        let style = GlobalStyle::new_with_manager("color: red;", &manager)
+            .expect("Failed to create style.");
+        assert_ne!(style.id().to_string(), id);
+    }
+}
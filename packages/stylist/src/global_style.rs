@@ -30,6 +30,7 @@ impl GlobalStyle {
         use crate::ast::Sheet;
 
         let prefix = format!("{}-global", manager.prefix());
+        let dependencies = css.dependencies();
         let css = css.try_to_sheet()?;
 
         // Creates the StyleKey, return from registry if already cached.
@@ -37,6 +38,7 @@ impl GlobalStyle {
             is_global: true,
             prefix: prefix.into(),
             ast: css,
+            scope_keyframes: false,
         };
 
         let reg = manager.get_registry();
@@ -46,7 +48,11 @@ impl GlobalStyle {
             return Ok(Self { inner: m });
         }
 
-        let style_str = key.ast.to_style_str(None);
+        // Checks the stylesheet against the css property database in debug mode.
+        #[cfg(all(debug_assertions, feature = "validation"))]
+        key.ast.validate()?;
+
+        let style_str = manager.transform(&key.ast, true).to_style_str(None);
 
         // We parse the style str again in debug mode to ensure that interpolated values are
         // not corrupting the stylesheet.
@@ -57,11 +63,12 @@ impl GlobalStyle {
 
         let new_style = Self {
             inner: StyleContent {
-                is_global: true,
+                is_atomic: false,
                 id: StyleId(format!("{}-{}", key.prefix, get_entropy())),
                 style_str,
                 manager,
                 key: Rc::new(key),
+                dependencies,
             }
             .into(),
         };
@@ -0,0 +1,265 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::rc::Rc;
+
+#[cfg(all(debug_assertions, feature = "parser"))]
+use stylist_core::ResultDisplay;
+
+use crate::ast::{Bow, Rule, RuleBlockContent, ScopeContent, Sheet, StyleAttribute, ToStyleStr};
+use crate::manager::StyleManager;
+use crate::registry::StyleKey;
+use crate::style::{StyleContent, StyleId};
+use crate::utils::get_entropy;
+use crate::Result;
+
+/// A builder for [`Keyframes`].
+///
+/// Stops are rendered in the order they are added.
+#[derive(Debug, Clone, Default)]
+pub struct KeyframesBuilder {
+    stops: Vec<(f32, Vec<StyleAttribute>)>,
+}
+
+impl KeyframesBuilder {
+    /// Creates a builder without any stops.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a stop at `percentage` (`0.0` to `100.0`) of the animation with the given style
+    /// attributes.
+    pub fn stop<I, K, V>(mut self, percentage: f32, attrs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        let attrs = attrs
+            .into_iter()
+            .map(|(key, value)| StyleAttribute {
+                key: key.into(),
+                value: vec![value.into().into()].into(),
+            })
+            .collect();
+        self.stops.push((percentage, attrs));
+
+        self
+    }
+
+    /// Registers the keyframes on the default [`StyleManager`].
+    pub fn build(self) -> Result<Keyframes> {
+        self.build_with_manager(StyleManager::default())
+    }
+
+    /// Registers the keyframes on a custom [`StyleManager`].
+    pub fn build_with_manager<M>(self, manager: M) -> Result<Keyframes>
+    where
+        M: Into<StyleManager>,
+    {
+        Keyframes::create_impl(&self, manager.into())
+    }
+
+    // The name is left out of the registry key, so identical keyframes share a single name.
+    fn to_sheet(&self, name: Option<&str>) -> Sheet {
+        let frames = self
+            .stops
+            .iter()
+            .map(|(percentage, attrs)| {
+                let content = attrs
+                    .iter()
+                    .cloned()
+                    .map(RuleBlockContent::StyleAttr)
+                    .collect::<Vec<_>>();
+
+                RuleBlockContent::Rule(Bow::Boxed(Box::new(Rule {
+                    condition: vec![format!("{}%", percentage).into()].into(),
+                    content: content.into(),
+                })))
+            })
+            .collect::<Vec<_>>();
+
+        let condition = match name {
+            Some(m) => format!("@keyframes {}", m),
+            None => "@keyframes".to_string(),
+        };
+
+        Sheet::from(vec![ScopeContent::Rule(Rule {
+            condition: vec![condition.into()].into(),
+            content: frames.into(),
+        })])
+    }
+}
+
+/// A struct that represents a named `@keyframes` rule.
+///
+/// Keyframes are mounted with a generated unique name, which is printed by the
+/// [`Display`](fmt::Display) implementation, so they can be referenced directly in
+/// [`css!`](crate::css). Keyframes with the same stops share the same name.
+///
+/// ```
+/// use stylist::{css, Keyframes};
+///
+/// let fade_in = Keyframes::builder()
+///     .stop(0.0, [("opacity", "0")])
+///     .stop(100.0, [("opacity", "1")])
+///     .build()?;
+///
+/// let style = css!("animation: ${fade_in} 1s ease-in;", fade_in = fade_in);
+/// # Ok::<(), stylist::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keyframes {
+    inner: Rc<StyleContent>,
+}
+
+impl Keyframes {
+    // The big method is monomorphic, so less code duplication and code bloat through generics
+    // and inlining
+    fn create_impl(builder: &KeyframesBuilder, manager: StyleManager) -> Result<Self> {
+        let prefix = format!("{}-keyframes", manager.prefix());
+
+        // Creates the StyleKey, return from registry if already cached.
+        let key = StyleKey {
+            is_global: true,
+            prefix: prefix.into(),
+            ast: builder.to_sheet(None),
+            scope_keyframes: false,
+        };
+
+        let reg = manager.get_registry();
+        let mut reg = reg.borrow_mut();
+
+        if let Some(m) = reg.get(&key) {
+            return Ok(Self { inner: m });
+        }
+
+        // Checks the stylesheet against the css property database in debug mode.
+        #[cfg(all(debug_assertions, feature = "validation"))]
+        key.ast.validate()?;
+
+        let id = StyleId(format!("{}-{}", key.prefix, get_entropy()));
+
+        let style_str = manager
+            .transform(&builder.to_sheet(Some(&id)), true)
+            .to_style_str(None);
+
+        // We parse the style str again in debug mode to ensure that interpolated values are
+        // not corrupting the stylesheet.
+        #[cfg(all(debug_assertions, feature = "parser"))]
+        style_str
+            .parse::<Sheet>()
+            .expect_display("debug: Stylist failed to parse the keyframes");
+
+        let new_style = Self {
+            inner: StyleContent {
+                is_atomic: false,
+                id,
+                style_str,
+                manager,
+                key: Rc::new(key),
+                dependencies: Vec::new(),
+            }
+            .into(),
+        };
+
+        new_style.inner.manager().mount(&new_style.inner)?;
+
+        // Register the created Style.
+        reg.register(new_style.inner.clone());
+
+        Ok(new_style)
+    }
+
+    /// Creates a builder for keyframes.
+    pub fn builder() -> KeyframesBuilder {
+        KeyframesBuilder::new()
+    }
+
+    /// Returns the generated name of the keyframes.
+    pub fn name(&self) -> &str {
+        self.inner.id()
+    }
+
+    /// Get the generated `@keyframes` rule in `&str`.
+    ///
+    /// This is usually used for debug purposes or testing in non-wasm32 targets.
+    pub fn get_style_str(&self) -> &str {
+        self.inner.get_style_str()
+    }
+
+    /// Returns a reference of style key.
+    pub(crate) fn key(&self) -> Rc<StyleKey> {
+        self.inner.key()
+    }
+
+    /// Unregister the keyframes from style registry.
+    ///
+    /// After calling this method, the keyframes will be unmounted from DOM after all their clones
+    /// are freed.
+    pub fn unregister(&self) {
+        let reg = self.inner.manager().get_registry();
+        let mut reg = reg.borrow_mut();
+        reg.unregister(self.key());
+    }
+
+    /// Returns the [`StyleId`] for the keyframes.
+    pub fn id(&self) -> &StyleId {
+        self.inner.id()
+    }
+}
+
+impl fmt::Display for Keyframes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fade_in() -> KeyframesBuilder {
+        Keyframes::builder()
+            .stop(0.0, [("opacity", "0")])
+            .stop(62.5, [("opacity", "0.5"), ("transform", "scale(1.1)")])
+            .stop(100.0, [("opacity", "1")])
+    }
+
+    #[test]
+    fn test_keyframes() {
+        let keyframes = fade_in().build().expect("Failed to create Keyframes.");
+
+        assert_eq!(
+            keyframes.get_style_str(),
+            format!(
+                r#"@keyframes {name} {{
+    0% {{
+        opacity: 0;
+    }}
+    62.5% {{
+        opacity: 0.5;
+        transform: scale(1.1);
+    }}
+    100% {{
+        opacity: 1;
+    }}
+}}
+"#,
+                name = keyframes
+            )
+        );
+    }
+
+    #[test]
+    fn test_dedup() {
+        let a = fade_in().build().expect("Failed to create Keyframes.");
+        let b = fade_in().build().expect("Failed to create Keyframes.");
+        let c = fade_in()
+            .stop(100.0, [("color", "red")])
+            .build()
+            .expect("Failed to create Keyframes.");
+
+        assert_eq!(a.name(), b.name());
+        assert_ne!(a.name(), c.name());
+    }
+}
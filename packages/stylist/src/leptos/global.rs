@@ -0,0 +1,41 @@
+use leptos::prelude::*;
+
+use super::use_style_manager;
+use crate::{GlobalStyle, StyleSource};
+use stylist_core::ResultDisplay;
+
+/// A Global Style that will be applied to `<html />` tag, inspired by [emotion](https://emotion.sh).
+///
+/// The `css` attribute accepts any type that implements [`Into<StyleSource>`] and panics if the
+/// string failed to be parsed into a stylesheet. The style is unregistered when the component is
+/// removed.
+///
+/// # Example:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::leptos::Global;
+///
+/// #[component]
+/// fn App() -> impl IntoView {
+///     view! {
+///         <Global css="color: red;" />
+///         <div>"Hello World!"</div>
+///     }
+/// }
+/// ```
+#[component]
+pub fn Global(
+    /// The global stylesheet.
+    #[prop(into)]
+    css: StyleSource<'static>,
+) -> impl IntoView {
+    let global_style = GlobalStyle::new_with_manager(css, use_style_manager())
+        .expect_display("Failed to create style.");
+
+    // Cleanups run before the stored values of the owner are dropped.
+    let global_style = StoredValue::new_local(global_style);
+    on_cleanup(move || {
+        global_style.try_with_value(GlobalStyle::unregister);
+    });
+}
@@ -0,0 +1,73 @@
+//! This module contains leptos specific features.
+//!
+//! # Multi-threaded rendering
+//!
+//! **[`StyleManager`] and styles are not thread-safe.** They are stored with
+//! [`StoredValue::new_local`](leptos::prelude::StoredValue::new_local), which drops them with the
+//! component that created them and panics if they are accessed on another thread. If a server
+//! renders components on multiple threads, like a multi-threaded async runtime may, render each
+//! request on a single thread (e.g. with a `LocalSet`).
+
+use leptos::oco::Oco;
+use leptos::prelude::{use_context, LocalStorage, StoredValue, WithValue};
+
+/// A procedural macro to use a specialized, contextual [`css!`](crate::css) macro.
+///
+/// The [`css!`](crate::css) macro in the body of the function uses the style manager provided by
+/// [`ManagerProvider`], or the default style manager if there is none. Apply this attribute
+/// before the [`component`](::leptos::component) attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// use leptos::oco::Oco;
+/// use leptos::prelude::*;
+/// use stylist::leptos::styled_component_impl;
+///
+/// #[styled_component_impl]
+/// #[component]
+/// fn MyStyledComponent() -> impl IntoView {
+///     let class = Oco::from(css!("color: red;"));
+///     view! { <div class=class>"Hello World!"</div> }
+/// }
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::leptos_styled_component_impl as styled_component_impl;
+
+use crate::manager::StyleManager;
+use crate::{Style, StyleSource};
+
+mod global;
+mod provider;
+
+pub use global::{Global, GlobalProps};
+pub use provider::{ManagerProvider, ManagerProviderProps};
+
+/// Returns the style manager provided by [`ManagerProvider`], or the default style manager if
+/// there is none.
+///
+/// # Panics
+///
+/// Panics if the component is rendered on another thread than the [`ManagerProvider`]. See the
+/// [module documentation](self#multi-threaded-rendering).
+pub fn use_style_manager() -> StyleManager {
+    use_context::<StoredValue<StyleManager, LocalStorage>>()
+        .and_then(|m| m.try_with_value(StyleManager::clone))
+        .unwrap_or_default()
+}
+
+/// Converts a [`Style`] into its class name, which can be used as the `class` of an element.
+impl From<Style> for Oco<'static, str> {
+    fn from(style: Style) -> Self {
+        style.get_class_name().to_string().into()
+    }
+}
+
+/// Creates a [`Style`] and converts it into its class name, which can be used as the `class` of
+/// an element.
+impl From<StyleSource<'_>> for Oco<'static, str> {
+    fn from(style_src: StyleSource<'_>) -> Self {
+        style_src.to_style().get_class_name().to_string().into()
+    }
+}
@@ -0,0 +1,41 @@
+use leptos::prelude::*;
+
+use crate::manager::StyleManager;
+
+/// A Context Provider to provide a custom [`StyleManager`] to child components.
+///
+/// # Multi-threaded rendering
+///
+/// **Child components must be rendered on the same thread as the provider.** The style manager
+/// panics if it is accessed on another thread. See the
+/// [module documentation](super#multi-threaded-rendering).
+///
+/// # Example:
+///
+/// ```
+/// use leptos::prelude::*;
+/// use stylist::leptos::ManagerProvider;
+/// use stylist::manager::StyleManager;
+///
+/// #[component]
+/// fn App(children: Children) -> impl IntoView {
+///     let mgr = StyleManager::builder().prefix("my-styles".into()).build().unwrap();
+///
+///     view! {
+///         <ManagerProvider manager=mgr>
+///             {children()}
+///         </ManagerProvider>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ManagerProvider(
+    /// The style manager provided to child components.
+    manager: StyleManager,
+    children: Children,
+) -> impl IntoView {
+    // The style manager is dropped with the owner of the provider.
+    provide_context(StoredValue::new_local(manager));
+
+    children()
+}
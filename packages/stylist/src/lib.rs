@@ -113,6 +113,12 @@
 //! }
 //! ```
 //!
+//! Keyframes defined with the [`css!`] and [`style!`] macros are scoped to the style that defines
+//! them: `mymove` is renamed to `{class-name}-mymove` and references in `animation` and
+//! `animation-name` of the same style are updated accordingly. Keyframes of styles created at
+//! runtime, e.g. with [`Style::new`], and of a [`GlobalStyle`] keep their name and can be shared
+//! between styles.
+//!
 //! ```css
 //! @media only screen and (max-width: 600px) {
 //!   background-color: #303040;
@@ -133,9 +139,32 @@
 //! }
 //! ```
 //!
+//! ### DOM API
+//!
+//! Without a framework integration, [`Style::apply_to`] adds the class of a style to an element
+//! until the returned guard is dropped. See the [`dom`] module for details.
+//!
 //! ### Theming
 //!
-//! There's theming example using
+//! Structured themes implement [`Theme`], usually with `#[derive(Theme)]`. Their tokens are
+//! exposed as CSS custom properties and can be referenced in macros by path:
+//!
+//! ```
+//! use stylist::{css, Theme};
+//!
+//! #[derive(Debug, Clone, PartialEq, Theme)]
+//! struct MyTheme {
+//!     primary_color: String,
+//! }
+//!
+//! // `color: var(--primary-color);`
+//! let style = css!("color: ${MyTheme::PRIMARY_COLOR};");
+//! ```
+//!
+//! With yew integration, [`ThemeProvider`](yew::ThemeProvider) mounts a theme for its children and
+//! [`use_theme`](yew::use_theme) retrieves it in child components.
+//!
+//! There's also a theming example using
 //! [Yew Context API](https://github.com/futursolo/stylist-rs/tree/master/examples/yew-theme-context).
 //!
 //! ## Features Flags
@@ -145,29 +174,72 @@
 //!   class name. Disabling this flag will opt for a class name that is counter-based.
 //! - `yew_integration`: This flag enables yew integration, which implements [`Classes`](::yew::html::Classes) for
 //!   [`Style`] and provides a [`Global`](yew::Global) component for applying global styles.
+//! - `leptos_integration`: This flag enables leptos integration, which provides a
+//!   [`ManagerProvider`](leptos::ManagerProvider), a [`Global`](leptos::Global) component and
+//!   class conversions for [`Style`].
+//! - `dioxus_integration`: This flag enables dioxus integration, which provides a
+//!   [`ManagerProvider`](dioxus::ManagerProvider), a [`Global`](dioxus::Global) component and
+//!   lets [`Style`] be used as the value of `class` attributes.
+//! - `sycamore_integration`: This flag enables sycamore integration, which provides a
+//!   [`ManagerProvider`](sycamore::ManagerProvider), a [`Global`](sycamore::Global) component and
+//!   lets [`Style`] be used as the value of `class` attributes.
+//! - `validation`: This flag checks property names and values against a bundled database of CSS
+//!   properties. Unknown properties become compile errors in macros and errors in debug mode at
+//!   runtime. Properties missing from the database can be allowed with the
+//!   `STYLIST_ALLOWED_PROPERTIES` environment variable, see the [`validation`] module.
+//! - `autoprefixer`: This flag enables adding vendor prefixes to styles for the browsers
+//!   configured with [`StyleManagerBuilder::targets`](manager::StyleManagerBuilder::targets).
+//! - `rtl`: This flag enables flipping styles for right-to-left languages, with the direction
+//!   configured with [`StyleManagerBuilder::direction`](manager::StyleManagerBuilder::direction).
+//! - `serde`: This flag implements `Serialize` and `Deserialize` for the types of the [`ast`]
+//!   module, so precompiled stylesheets can be stored or sent over the wire. Deserializing does not
+//!   require the `parser` flag.
 
-#[cfg(any(feature = "yew_use_media_query", target_arch = "wasm32"))]
+#[cfg(target_arch = "wasm32")]
 mod arch;
+mod atomic;
 
 pub mod manager;
+#[cfg(feature = "yew_use_media_query")]
+mod media_query;
+mod priority;
+mod recipe;
 mod registry;
 
 pub mod ast;
+pub mod dom;
 mod global_style;
+mod keyframes;
 mod style;
 mod style_src;
+mod theme;
 mod utils;
 mod yield_style;
 
 pub use global_style::GlobalStyle;
-pub use style::Style;
+pub use keyframes::{Keyframes, KeyframesBuilder};
+pub use recipe::{Recipe, RecipeBuilder};
+pub use style::{ClassSelector, Style};
 pub use style_src::StyleSource;
+pub use theme::{Theme, ThemeToken};
 pub use yield_style::YieldStyle;
 
 #[cfg_attr(documenting, doc(cfg(feature = "yew_integration")))]
 #[cfg(feature = "yew_integration")]
 pub mod yew;
 
+#[cfg_attr(documenting, doc(cfg(feature = "leptos_integration")))]
+#[cfg(feature = "leptos_integration")]
+pub mod leptos;
+
+#[cfg_attr(documenting, doc(cfg(feature = "dioxus_integration")))]
+#[cfg(feature = "dioxus_integration")]
+pub mod dioxus;
+
+#[cfg_attr(documenting, doc(cfg(feature = "sycamore_integration")))]
+#[cfg(feature = "sycamore_integration")]
+pub mod sycamore;
+
 #[cfg_attr(documenting, doc(cfg(feature = "macros")))]
 #[cfg(feature = "macros")]
 pub mod macros;
@@ -222,5 +294,92 @@ pub use stylist_macros::global_style;
 #[cfg(feature = "macros")]
 pub use stylist_macros::css;
 
+/// A procedural macro that creates a struct with typed access to the classes of a stylesheet,
+/// like a CSS module.
+///
+/// Each class selector in the stylesheet becomes a field with a unique class name, with dashes
+/// in the class replaced by underscores. Classes that are keywords become raw identifiers, e.g.:
+/// `r#type` for `.type`. All classes are registered as a single [`Style`]. No element has the
+/// class of that style itself, so `&.active` is the same as `.active`.
+/// Referencing a class that is not defined in the stylesheet is a compile error. The stylesheet
+/// is parsed with the same parser as string literals and supports the same named arguments as
+/// [`css!`].
+///
+/// # Example
+///
+/// ```
+/// use stylist::css_module;
+///
+/// css_module!(pub struct CardStyles, r#"
+///     .title {
+///         font-weight: bold;
+///     }
+///     .body, .footer-link:hover {
+///         color: ${color};
+///     }
+/// "#, color = "red");
+///
+/// let styles = CardStyles::new()?;
+/// assert_eq!(
+///     styles.footer_link,
+///     format!("{}-footer-link", styles.style().get_class_name())
+/// );
+/// # Ok::<(), stylist::Error>(())
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::css_module;
+
+/// A procedural macro that reads a stylesheet from a file into a [`StyleSource`] at compile time.
+///
+/// The path is relative to the directory of the `Cargo.toml` of the crate, and the crate is
+/// rebuilt when the file changes. The file is parsed with the same parser as string literals and
+/// supports the same named arguments as [`css!`]. Errors are reported with the path, line and
+/// column in the file.
+///
+/// # Example
+///
+/// ```
+/// use stylist::{include_css, Style};
+///
+/// let color = "red";
+/// let style = Style::new(include_css!(
+///     "tests/styles/button.css",
+///     padding = "4px",
+///     color = color
+/// ))?;
+/// # Ok::<(), stylist::Error>(())
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::include_css;
+
+/// A procedural macro to derive [`Theme`] for a struct with named fields.
+///
+/// Each field becomes a token with the kebab-cased field name, whose value is the field
+/// formatted with [`Display`](std::fmt::Display). An upper cased associated constant of type
+/// [`ThemeToken`] is created for each field, so tokens can be referenced as
+/// `${MyTheme::FIELD_NAME}` in [`css!`] and related macros.
+///
+/// Please see the documentation of [`Theme`] for an example.
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::Theme;
+
 #[doc(inline)]
 pub use stylist_core::{Error, Result};
+
+#[cfg_attr(documenting, doc(cfg(feature = "validation")))]
+#[cfg(feature = "validation")]
+#[doc(inline)]
+pub use stylist_core::validation;
+
+#[cfg_attr(documenting, doc(cfg(feature = "autoprefixer")))]
+#[cfg(feature = "autoprefixer")]
+#[doc(inline)]
+pub use stylist_core::autoprefixer;
+
+#[cfg_attr(documenting, doc(cfg(feature = "rtl")))]
+#[cfg(feature = "rtl")]
+#[doc(inline)]
+pub use stylist_core::rtl;
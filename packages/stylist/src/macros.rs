@@ -14,6 +14,9 @@
 //! Interpolated strings are denoted with `${ident}` and any type that implements [`Display`] can be
 //! used as value. Only named argument are supported at this moment.
 //!
+//! A path such as `${MyTheme::PRIMARY_COLOR}` is not looked up in the argument list, but refers
+//! to a constant in scope instead. This is mostly used to reference [`ThemeToken`](crate::ThemeToken)s.
+//!
 //! If you do need to print a `${` sequence, you may use `$${` to escape to a `${`.
 //!
 //! ### Example
@@ -78,6 +81,16 @@
 //! syntax. In distinction, the braces contain a rust expression of any type implementing [`Display`]
 //! will be evaluated in the surrounding context.
 //!
+//! The supported at-rules are `@media`, `@supports` and `@keyframes`.
+//!
+//! ## Keyframes
+//!
+//! Keyframes defined in a style created by [`css!`](crate::css), [`style!`](crate::style),
+//! [`include_css!`](crate::include_css) or [`css_module!`](crate::css_module) are scoped to the
+//! style, so references in `animation` and `animation-name` of the same style are renamed with
+//! the keyframes. Define keyframes shared between styles in a [`GlobalStyle`](crate::GlobalStyle)
+//! or with [`Keyframes`](crate::Keyframes).
+//!
 //! ## Known Limitations
 //!
 //! Due to the tokenizer of the Rust complier, there are some quirks with literals. For example, `4em` would be
@@ -85,19 +98,15 @@
 //! string interpolation as in `${"4em"}`. Similarly, some color hash-tokens like `#44444e` as misinterpreted,
 //! use the same workaround here: `${"#44444e"}`.
 //!
-//! The stable Rust tokenizer also currently offers no way to inspect whitespace between tokens, as tracked in
-//! [the Span inspection API issue](https://github.com/rust-lang/rust/issues/54725). This means that, e.g. the two
-//! selectors `.class-a.class-b` and `.class-a .class-b` can not be differentiated. **The macro errs on side of the
-//! former input without any spaces.** If you meant to write the latter, use `.class-a *.class-b`.
+//! Whitespace in the output follows the macro input. For example, `.class-a.class-b` and
+//! `.class-a .class-b` are different selectors, and `calc(100% - 10px)` keeps the spaces around
+//! `-`. Selectors are parsed structurally, so combinators are always printed with surrounding spaces
+//! (`a > b`) and attribute selectors are printed without spaces (`[lang|="en"]`).
 //!
-//! To be more specific, a space is inserted between two tokens `L R` iff (regardless of the space being present in the macro input):
+//! For tokens created by another macro, whose whitespace is unknown, a space is inserted between
+//! two tokens `L R` iff:
 //! - `L` is either a closing bracket `)}]`, an identifier `red`, a literal string `"\e600"` or number `3px`, or the '*' character.
 //! - `R` is either an identifier, a literal string or number, the '*' or '#' character.
-//! Spacing around interpolation is ignored regardless.
-//!
-//! Be aware that the above is subject to change once the Span API is stabilized. To avoid future rewriting, use spacing
-//! in your source code that follows the same rules. Refer to the associated [bug report](https://github.com/futursolo/stylist-rs/issues/41)
-//! to discuss this limitation and offer additional suggestions
 //!
 //! ## Note
 //!
@@ -140,6 +149,55 @@
 //! println!("{}", style.get_style_str());
 //! ```
 //!
+//! ## Composition
+//!
+//! Both syntaxes can splice the contents of another source into the stylesheet with
+//! `...${base}`. The source can be anything that converts into a [`StyleSource`](crate::StyleSource),
+//! such as the output of [`css!`](crate::css) or a [`Sheet`](crate::ast::Sheet).
+//!
+//! ```
+//! use stylist::css;
+//!
+//! let base = css!(
+//!     color: red;
+//!     &:hover {
+//!         color: blue;
+//!     }
+//! );
+//!
+//! let s = css!(
+//!     ...${base}
+//!     color: green;
+//! );
+//! ```
+//!
+//! The spliced contents are scoped to the same class, so a Current Selector (`&`) in `base`
+//! refers to the element of the composed style. Declarations after the spread take precedence
+//! over declarations in `base`. Spreads are only supported at the top level of a stylesheet.
+//!
+//! Spread sources are parsed when the style is created, so a string that fails to parse is
+//! returned as an error by [`Style::new`](crate::Style::new). As [`sheet!`](crate::ast::sheet) cannot
+//! return an error, it panics instead.
+//!
+//! ## Referencing Other Styles
+//!
+//! A [`Style`](crate::Style) or [`StyleId`](crate::manager::StyleId) interpolated into a
+//! selector is printed as a class selector, e.g.: `${card}:hover & { .. }`. See
+//! [`ClassSelector`](crate::ClassSelector) for details.
+//!
+//! ## Validation
+//!
+//! If feature `validation` is enabled, both syntaxes check property names against a bundled
+//! database of CSS properties, and values of keyword based properties (e.g.: `display`) against
+//! their allowed keywords. Interpolated names and values are not checked.
+//!
+//! With the inline syntax, unknown properties are reported at the span of the offending property.
+//! With the string literal syntax, they are reported at the literal, with the line and column of
+//! the offending property in the literal.
+//!
+//! Properties missing from the database can be allowed with the `STYLIST_ALLOWED_PROPERTIES`
+//! environment variable. See the [`validation`](crate::validation) module for details.
+//!
 //! ## Security Notice
 //!
 //! Stylist currently does not check or escape the content of interpolated strings. It is possible
@@ -151,6 +209,79 @@
 //! [inline]: #inline
 //! [`Display`]: std::fmt::Display
 
+// Used by `...${base}` to splice another source into a stylesheet.
+#[doc(hidden)]
+pub fn spread_source<'a, S>(source: &S) -> crate::StyleSource<'a>
+where
+    S: Clone + Into<crate::StyleSource<'a>>,
+{
+    source.clone().into()
+}
+
+// Used by macros to keep the styles interpolated into selectors of a source mounted with the
+// style created from it.
+#[doc(hidden)]
+pub fn collect_dependencies<'a, F, S>(f: F) -> crate::StyleSource<'a>
+where
+    F: FnOnce() -> S,
+    S: Into<crate::StyleSource<'a>>,
+{
+    let (source, dependencies) = crate::style::collect_dependencies(f);
+    source.into().with_dependencies(dependencies)
+}
+
+// Used by `sheet!` to return a sheet for sources with spreads.
+#[doc(hidden)]
+pub trait IntoSheet {
+    fn into_sheet(self) -> crate::ast::Sheet;
+}
+
+impl IntoSheet for crate::ast::Sheet {
+    fn into_sheet(self) -> crate::ast::Sheet {
+        self
+    }
+}
+
+impl IntoSheet for crate::StyleSource<'_> {
+    fn into_sheet(self) -> crate::ast::Sheet {
+        use stylist_core::ResultDisplay;
+
+        self.try_to_sheet().expect_display("Failed to spread style")
+    }
+}
+
+// Interpolations in selectors print types implementing `ClassSelector` as class selectors and
+// fall back to `Display` for all other types.
+#[doc(hidden)]
+pub mod selector {
+    use std::fmt;
+
+    use crate::ClassSelector;
+
+    #[derive(Debug)]
+    pub struct Wrap<'a, T: ?Sized>(pub &'a T);
+
+    pub trait ViaClassSelector {
+        fn __stylist_selector(&self) -> String;
+    }
+
+    impl<T: ClassSelector + ?Sized> ViaClassSelector for Wrap<'_, T> {
+        fn __stylist_selector(&self) -> String {
+            self.0.class_selector()
+        }
+    }
+
+    pub trait ViaDisplay {
+        fn __stylist_selector(&self) -> String;
+    }
+
+    impl<T: fmt::Display + ?Sized> ViaDisplay for &Wrap<'_, T> {
+        fn __stylist_selector(&self) -> String {
+            self.0.to_string()
+        }
+    }
+}
+
 #[doc(hidden)]
 pub mod vendor {
     pub use once_cell;
@@ -7,35 +7,123 @@
 
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::fmt;
 use std::rc::Rc;
 
 use once_cell::unsync::Lazy;
 use stylist_core::ResultDisplay;
 use web_sys::Node;
 
+use crate::ast::Sheet;
+#[cfg(feature = "autoprefixer")]
+use crate::autoprefixer::Targets;
+#[cfg(feature = "yew_use_media_query")]
+use crate::media_query::MediaQueryRegistry;
 use crate::registry::StyleRegistry;
 use crate::style::StyleContent;
 pub use crate::style::StyleId;
 use crate::Result;
 
+/// A transform applied to all stylesheets of a [`StyleManager`] before they are rendered.
+///
+/// This trait is implemented for all closures with the signature `Fn(Sheet) -> Sheet`.
+///
+/// ```
+/// use stylist::manager::StyleManager;
+///
+/// // Replaces `16px` with `1rem` in all values.
+/// let manager = StyleManager::builder()
+///     .plugin(|sheet: stylist::ast::Sheet| {
+///         sheet.map(|attr| {
+///             for frag in attr.value.to_mut().iter_mut() {
+///                 *frag = frag.inner.replace("16px", "1rem").into();
+///             }
+///         })
+///     })
+///     .build()
+///     .expect("Failed to create manager.");
+/// ```
+pub trait Plugin {
+    /// Transforms the stylesheet.
+    fn transform(&self, sheet: Sheet) -> Sheet;
+}
+
+impl<F> Plugin for F
+where
+    F: Fn(Sheet) -> Sheet,
+{
+    fn transform(&self, sheet: Sheet) -> Sheet {
+        self(sheet)
+    }
+}
+
+#[derive(Clone, Default)]
+struct Plugins(Vec<Rc<dyn Plugin>>);
+
+impl fmt::Debug for Plugins {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Plugins")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+/// The text direction that styles are generated for.
+#[cfg_attr(documenting, doc(cfg(feature = "rtl")))]
+#[cfg(feature = "rtl")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Styles are generated as written.
+    Ltr,
+    /// Styles are flipped for right-to-left languages.
+    Rtl,
+    /// Styles are generated as written, followed by flipped overrides that apply to elements
+    /// in a `[dir=rtl]` container.
+    Both,
+}
+
 /// A builder for [`StyleManager`].
 #[derive(Debug, Clone)]
 pub struct StyleManagerBuilder {
     registry: Rc<RefCell<StyleRegistry>>,
 
+    #[cfg(feature = "yew_use_media_query")]
+    media_queries: Rc<RefCell<MediaQueryRegistry>>,
+
     prefix: Cow<'static, str>,
     container: Option<Node>,
 
     append: bool,
+
+    atomic: bool,
+
+    priority: usize,
+
+    plugins: Plugins,
+
+    #[cfg(feature = "rtl")]
+    direction: Direction,
+
+    #[cfg(feature = "autoprefixer")]
+    targets: Option<Targets>,
 }
 
 impl Default for StyleManagerBuilder {
     fn default() -> Self {
         Self {
             registry: Rc::default(),
+            #[cfg(feature = "yew_use_media_query")]
+            media_queries: Rc::default(),
             prefix: "stylist".into(),
             container: None,
             append: true,
+            atomic: false,
+            priority: 0,
+            plugins: Plugins::default(),
+            #[cfg(feature = "rtl")]
+            direction: Direction::Ltr,
+            #[cfg(feature = "autoprefixer")]
+            targets: None,
         }
     }
 }
@@ -76,6 +164,85 @@ impl StyleManagerBuilder {
         self
     }
 
+    /// Set whether styles are split into atomic styles.
+    ///
+    /// When set to `true`, each declaration of a [`Style`](crate::Style) becomes a separate
+    /// style with a single declaration, which is shared by all styles with the same declaration
+    /// in the same context. [`Style::get_class_name`](crate::Style::get_class_name) returns a
+    /// space-separated list of the class names of these atomic styles.
+    ///
+    /// The class selector of an atomic style is repeated for each selector and at-rule of its
+    /// context, so declarations with selectors take precedence over plain declarations and
+    /// declarations in at-rules take precedence over both, regardless of the order in which
+    /// atomic styles are mounted. In the same context, longhand properties (`padding-left`) take
+    /// precedence over their shorthands (`padding`) the same way. If a declaration is repeated or
+    /// a shorthand follows its longhands in the same context, only the last declaration is kept.
+    /// Keyframes are not split.
+    ///
+    /// Styles that rely on a single class name, such as the classes of `css_module!`, are not
+    /// supported by atomic styles.
+    ///
+    /// Default: `false`
+    pub fn atomic(mut self, value: bool) -> Self {
+        self.atomic = value;
+
+        self
+    }
+
+    /// Set the priority of styles managed by this manager.
+    ///
+    /// The class selector of each style is repeated `priority` more times, which raises the
+    /// specificity of every selector of the style by `priority` classes. Styles of a manager with
+    /// a higher priority override styles of a manager with a lower priority, regardless of the
+    /// order in which they are mounted. This applies to atomic styles as well.
+    ///
+    /// Styles created with different managers are never shared, so the same stylesheet can be
+    /// used with different priorities.
+    ///
+    /// Default: `0`
+    pub fn priority(mut self, value: usize) -> Self {
+        self.priority = value;
+
+        self
+    }
+
+    /// Add a [`Plugin`] to transform stylesheets before they are rendered.
+    ///
+    /// Plugins are applied in the order they are added.
+    pub fn plugin<P>(mut self, value: P) -> Self
+    where
+        P: Plugin + 'static,
+    {
+        self.plugins.0.push(Rc::new(value));
+
+        self
+    }
+
+    /// Set the text direction that styles are generated for.
+    ///
+    /// Default: [`Direction::Ltr`]
+    #[cfg_attr(documenting, doc(cfg(feature = "rtl")))]
+    #[cfg(feature = "rtl")]
+    pub fn direction(mut self, value: Direction) -> Self {
+        self.direction = value;
+
+        self
+    }
+
+    /// Set the browsers that styles should support.
+    ///
+    /// When set, vendor prefixed declarations, selectors and at-rules are added to all styles
+    /// managed by this manager before they are mounted.
+    ///
+    /// Default: `None`
+    #[cfg_attr(documenting, doc(cfg(feature = "autoprefixer")))]
+    #[cfg(feature = "autoprefixer")]
+    pub fn targets(mut self, value: Targets) -> Self {
+        self.targets = Some(value);
+
+        self
+    }
+
     /// Build the [`StyleManager`].
     #[allow(unused_mut)]
     pub fn build(mut self) -> Result<StyleManager> {
@@ -113,11 +280,70 @@ impl StyleManager {
         self.inner.container.clone()
     }
 
+    /// Whether the managed [`Style`](crate::Style) instances are split into atomic styles.
+    pub fn is_atomic(&self) -> bool {
+        self.inner.atomic
+    }
+
+    /// The priority of the managed [`Style`](crate::Style) instances.
+    pub fn priority(&self) -> usize {
+        self.inner.priority
+    }
+
+    /// The text direction that the managed [`Style`](crate::Style) instances are generated for.
+    #[cfg_attr(documenting, doc(cfg(feature = "rtl")))]
+    #[cfg(feature = "rtl")]
+    pub fn direction(&self) -> Direction {
+        self.inner.direction
+    }
+
+    /// The browsers that the managed [`Style`](crate::Style) instances should support.
+    #[cfg_attr(documenting, doc(cfg(feature = "autoprefixer")))]
+    #[cfg(feature = "autoprefixer")]
+    pub fn targets(&self) -> Option<&Targets> {
+        self.inner.targets.as_ref()
+    }
+
+    /// Applies transforms configured on this manager to a stylesheet before it is rendered.
+    ///
+    /// Plugins are applied first, so vendor prefixes are also added to their output.
+    ///
+    /// `is_global` should be `true` if the stylesheet is not scoped to a class name.
+    #[allow(unused_variables)]
+    pub(crate) fn transform(&self, sheet: &Sheet, is_global: bool) -> Sheet {
+        let sheet = self
+            .inner
+            .plugins
+            .0
+            .iter()
+            .fold(sheet.clone(), |sheet, plugin| plugin.transform(sheet));
+
+        #[cfg(feature = "rtl")]
+        let sheet = match self.inner.direction {
+            Direction::Ltr => sheet,
+            Direction::Rtl => sheet.to_rtl(),
+            Direction::Both => sheet.with_rtl_overrides(is_global),
+        };
+
+        #[cfg(feature = "autoprefixer")]
+        if let Some(ref m) = self.inner.targets {
+            return sheet.autoprefix(m);
+        }
+
+        sheet
+    }
+
     /// Get the Registry instance.
     pub(crate) fn get_registry(&self) -> Rc<RefCell<StyleRegistry>> {
         self.inner.registry.clone()
     }
 
+    /// Get the registry of media queries, which shares listeners between components.
+    #[cfg(feature = "yew_use_media_query")]
+    pub(crate) fn get_media_query_registry(&self) -> Rc<RefCell<MediaQueryRegistry>> {
+        self.inner.media_queries.clone()
+    }
+
     /// Mount the [`Style`](crate::Style) into the DOM tree.
     #[cfg(target_arch = "wasm32")]
     pub(crate) fn mount(&self, content: &StyleContent) -> Result<()> {
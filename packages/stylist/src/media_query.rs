@@ -0,0 +1,158 @@
+//! Shares one listener per media query between all subscribers of a [`StyleManager`].
+//!
+//! [`StyleManager`]: crate::manager::StyleManager
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+use gloo_events::EventListener;
+use web_sys::MediaQueryList;
+
+type Callback = Rc<dyn Fn(bool)>;
+
+/// A media query and its subscribers, which is dropped with its listener after all
+/// subscriptions are dropped.
+struct SharedQuery {
+    list: MediaQueryList,
+    subscribers: RefCell<Vec<(usize, Callback)>>,
+    next_id: Cell<usize>,
+    listener: RefCell<Option<EventListener>>,
+}
+
+impl fmt::Debug for SharedQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedQuery")
+            .field("list", &self.list)
+            .field("subscribers", &self.subscribers.borrow().len())
+            .finish()
+    }
+}
+
+impl SharedQuery {
+    fn notify(&self) {
+        let matches = self.list.matches();
+
+        // Subscribers may subscribe or unsubscribe in their callbacks.
+        let callbacks = self
+            .subscribers
+            .borrow()
+            .iter()
+            .map(|(_, m)| m.clone())
+            .collect::<Vec<_>>();
+
+        for callback in callbacks {
+            callback(matches);
+        }
+    }
+}
+
+/// A subscription to a media query, which unsubscribes when it is dropped.
+#[derive(Debug)]
+pub(crate) struct MediaQuerySubscription {
+    query: Rc<SharedQuery>,
+    id: usize,
+}
+
+impl MediaQuerySubscription {
+    /// Whether the media query currently matches.
+    pub fn matches(&self) -> bool {
+        self.query.list.matches()
+    }
+}
+
+impl Drop for MediaQuerySubscription {
+    fn drop(&mut self) {
+        self.query
+            .subscribers
+            .borrow_mut()
+            .retain(|(id, _)| *id != self.id);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    use crate::arch::window;
+
+    window().ok()?.match_media(query).ok().flatten()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[allow(unused_variables)]
+fn match_media(query: &str) -> Option<MediaQueryList> {
+    // There is no window on non-wasm targets, e.g. when rendering on the server.
+    None
+}
+
+/// The registry of media queries of a [`StyleManager`](crate::manager::StyleManager).
+#[derive(Debug, Default)]
+pub(crate) struct MediaQueryRegistry {
+    queries: HashMap<String, Weak<SharedQuery>>,
+}
+
+impl MediaQueryRegistry {
+    /// Returns whether `query` currently matches, or `None` if there is no window to evaluate
+    /// it in.
+    pub fn matches(&self, query: &str) -> Option<bool> {
+        match self.queries.get(query).and_then(Weak::upgrade) {
+            Some(m) => Some(m.list.matches()),
+            None => match_media(query).map(|m| m.matches()),
+        }
+    }
+
+    /// Calls `f` with whether `query` matches every time the result changes, until the returned
+    /// subscription is dropped.
+    ///
+    /// Returns `None` if there is no window to evaluate the query in.
+    pub fn subscribe<F>(&mut self, query: &str, f: F) -> Option<MediaQuerySubscription>
+    where
+        F: Fn(bool) + 'static,
+    {
+        let shared = match self.queries.get(query).and_then(Weak::upgrade) {
+            Some(m) => m,
+            None => {
+                let shared = Rc::new(SharedQuery {
+                    list: match_media(query)?,
+                    subscribers: RefCell::default(),
+                    next_id: Cell::default(),
+                    listener: RefCell::default(),
+                });
+
+                let weak = Rc::downgrade(&shared);
+                let listener = EventListener::new(&shared.list, "change", move |_event| {
+                    if let Some(m) = weak.upgrade() {
+                        m.notify();
+                    }
+                });
+                *shared.listener.borrow_mut() = Some(listener);
+
+                // Removes queries whose subscriptions are all dropped.
+                self.queries.retain(|_, m| m.strong_count() > 0);
+                self.queries
+                    .insert(query.to_string(), Rc::downgrade(&shared));
+
+                shared
+            }
+        };
+
+        let id = shared.next_id.get();
+        shared.next_id.set(id + 1);
+        shared.subscribers.borrow_mut().push((id, Rc::new(f)));
+
+        Some(MediaQuerySubscription { query: shared, id })
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_window() {
+        let mut registry = MediaQueryRegistry::default();
+
+        assert_eq!(registry.matches("(min-width: 600px)"), None);
+        assert!(registry.subscribe("(min-width: 600px)", |_| {}).is_none());
+    }
+}
@@ -0,0 +1,136 @@
+//! Raises the specificity of stylesheets by repeating the class selector of a style.
+
+use crate::ast::{Block, Rule, Selector, Sheet, VisitorMut};
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Substitutes the Current Selector in `selector` with itself repeated `times` times, following
+/// the scoping rules of [`Style`](crate::Style).
+///
+/// A Current Selector with a suffix, like `&-title`, is repeated with its suffix.
+pub(crate) fn repeat_current_selector(selector: &Selector, times: usize) -> String {
+    let selector = selector
+        .fragments
+        .iter()
+        .map(|m| m.inner.as_ref())
+        .collect::<String>()
+        .replace(":root", "&");
+
+    if !selector.contains('&') {
+        let current = "&".repeat(times);
+
+        return if selector.starts_with(':') {
+            format!("{}{}", current, selector)
+        } else {
+            format!("{} {}", current, selector)
+        };
+    }
+
+    let mut repeated = String::with_capacity(selector.len() * times);
+    let mut rest = selector.as_str();
+
+    while let Some(start) = rest.find('&') {
+        let (before, current) = rest.split_at(start);
+        let end = current[1..]
+            .find(|m| !is_ident_char(m))
+            .map(|m| m + 1)
+            .unwrap_or(current.len());
+        let (current, after) = current.split_at(end);
+
+        repeated.push_str(before);
+        for _ in 0..times {
+            repeated.push_str(current);
+        }
+        rest = after;
+    }
+    repeated.push_str(rest);
+
+    repeated
+}
+
+struct RepeatCurrent {
+    times: usize,
+}
+
+impl VisitorMut for RepeatCurrent {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        let condition = if block.condition.is_empty() {
+            vec![Selector::from(vec!["&".repeat(self.times).into()])]
+        } else {
+            block
+                .condition
+                .iter()
+                .map(|m| Selector::from(vec![repeat_current_selector(m, self.times).into()]))
+                .collect()
+        };
+
+        block.condition = condition.into();
+        block.visit_children_mut(self);
+    }
+
+    fn visit_rule_mut(&mut self, rule: &mut Rule) {
+        // Keyframes do not contain selectors.
+        let is_keyframes = rule.condition.first().map(|m| {
+            m.inner.starts_with("@keyframes") || m.inner.starts_with("@-webkit-keyframes")
+        }) == Some(true);
+
+        if !is_keyframes {
+            rule.visit_children_mut(self);
+        }
+    }
+}
+
+/// Creates a new stylesheet with the class selector of the style repeated `priority + 1`
+/// times, which adds `priority` classes to the specificity of every selector.
+pub(crate) fn with_priority(sheet: &Sheet, priority: usize) -> Sheet {
+    if priority == 0 {
+        return sheet.clone();
+    }
+
+    let mut sheet = sheet.clone();
+    RepeatCurrent {
+        times: priority + 1,
+    }
+    .visit_sheet_mut(&mut sheet);
+
+    sheet
+}
+
+#[cfg(all(test, feature = "parser"))]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[test]
+    fn test_with_priority() {
+        let sheet: Sheet = r#"
+            color: red;
+            &:hover, .child, &-title {
+                color: blue;
+            }
+            @media print {
+                display: none;
+            }
+        "#
+        .parse()
+        .expect("Failed to parse sheet.");
+
+        assert_eq!(
+            with_priority(&sheet, 1).to_style_str(Some("a")),
+            r#".a.a {
+    color: red;
+}
+.a.a:hover, .a.a .child, .a-title.a-title {
+    color: blue;
+}
+@media print {
+    .a.a {
+        display: none;
+    }
+}
+"#
+        );
+    }
+}
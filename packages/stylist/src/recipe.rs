@@ -0,0 +1,338 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::ast::Sheet;
+use crate::manager::StyleManager;
+use crate::{Result, Style, StyleSource};
+
+type Predicate<V> = Rc<dyn Fn(&V) -> bool>;
+
+/// A builder for [`Recipe`].
+pub struct RecipeBuilder<V> {
+    base: StyleSource<'static>,
+    variants: Vec<(Predicate<V>, StyleSource<'static>)>,
+    precompute: Vec<V>,
+    prefix: Option<Cow<'static, str>>,
+    manager: Option<StyleManager>,
+}
+
+impl<V> fmt::Debug for RecipeBuilder<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecipeBuilder")
+            .field("base", &self.base)
+            .field("variants", &self.variants.len())
+            .field("precompute", &self.precompute.len())
+            .field("prefix", &self.prefix)
+            .field("manager", &self.manager)
+            .finish()
+    }
+}
+
+impl<V: 'static> RecipeBuilder<V> {
+    /// Creates a builder with the styles that are applied to all combinations.
+    pub fn new<Css>(base: Css) -> Self
+    where
+        Css: Into<StyleSource<'static>>,
+    {
+        Self {
+            base: base.into(),
+            variants: Vec::new(),
+            precompute: Vec::new(),
+            prefix: None,
+            manager: None,
+        }
+    }
+
+    /// Adds a variant that is applied if the value returned by `accessor` equals `option`.
+    pub fn variant<F, A, Css>(mut self, accessor: F, option: A, css: Css) -> Self
+    where
+        F: Fn(&V) -> &A + 'static,
+        A: PartialEq + 'static,
+        Css: Into<StyleSource<'static>>,
+    {
+        self.variants
+            .push((Rc::new(move |m| *accessor(m) == option), css.into()));
+
+        self
+    }
+
+    /// Adds a compound variant that is applied if `predicate` returns `true`.
+    ///
+    /// This is usually used for styles that only apply to a combination of variants.
+    pub fn compound<F, Css>(mut self, predicate: F, css: Css) -> Self
+    where
+        F: Fn(&V) -> bool + 'static,
+        Css: Into<StyleSource<'static>>,
+    {
+        self.variants.push((Rc::new(predicate), css.into()));
+
+        self
+    }
+
+    /// Creates the styles of these combinations when the recipe is built.
+    pub fn precompute<I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+    {
+        self.precompute.extend(values);
+
+        self
+    }
+
+    /// Set the class prefix of the generated styles.
+    pub fn prefix(mut self, value: Cow<'static, str>) -> Self {
+        self.prefix = Some(value);
+
+        self
+    }
+
+    /// Set the [`StyleManager`] of the generated styles.
+    pub fn manager(mut self, value: StyleManager) -> Self {
+        self.manager = Some(value);
+
+        self
+    }
+
+    /// Builds the recipe and creates the styles of all precomputed combinations.
+    pub fn build(self) -> Result<Recipe<V>> {
+        let manager = self.manager.unwrap_or_default();
+        let prefix = self.prefix.unwrap_or_else(|| manager.prefix());
+
+        let variants = self
+            .variants
+            .into_iter()
+            .map(|(predicate, css)| Ok((predicate, css.try_to_sheet()?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let recipe = Recipe {
+            base: self.base.try_to_sheet()?,
+            variants,
+            prefix,
+            manager,
+            styles: RefCell::default(),
+        };
+
+        for value in self.precompute.iter() {
+            recipe.style(value)?;
+        }
+
+        Ok(recipe)
+    }
+}
+
+/// A set of styles that are selected with typed variants.
+///
+/// A recipe consists of a base style, variants that are applied if a field of the variants
+/// struct equals an option and compound variants that are applied if a predicate matches. The
+/// defaults of a recipe are the [`Default`] implementation of the variants struct.
+///
+/// Each combination of matching variants is compiled into a single [`Style`], which is
+/// cached by the recipe. Variants are applied in the order they are added, so later variants
+/// take precedence over earlier variants.
+///
+/// ```
+/// use stylist::{css, Recipe};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Size {
+///     Small,
+///     Large,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct ButtonVariants {
+///     size: Size,
+///     danger: bool,
+/// }
+///
+/// impl Default for ButtonVariants {
+///     fn default() -> Self {
+///         Self {
+///             size: Size::Small,
+///             danger: false,
+///         }
+///     }
+/// }
+///
+/// let button = Recipe::builder(css!(border: none;))
+///     .variant(|m: &ButtonVariants| &m.size, Size::Small, css!(padding: 2px;))
+///     .variant(|m: &ButtonVariants| &m.size, Size::Large, css!(padding: 8px;))
+///     .variant(|m: &ButtonVariants| &m.danger, true, css!(color: red;))
+///     .compound(
+///         |m: &ButtonVariants| m.size == Size::Large && m.danger,
+///         css!(font-weight: bold;),
+///     )
+///     .precompute([ButtonVariants::default()])
+///     .build()?;
+///
+/// let style = button.style(&ButtonVariants {
+///     danger: true,
+///     ..Default::default()
+/// })?;
+/// # Ok::<(), stylist::Error>(())
+/// ```
+pub struct Recipe<V> {
+    base: Sheet,
+    variants: Vec<(Predicate<V>, Sheet)>,
+    prefix: Cow<'static, str>,
+    manager: StyleManager,
+    styles: RefCell<HashMap<Vec<usize>, Style>>,
+}
+
+impl<V> fmt::Debug for Recipe<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recipe")
+            .field("base", &self.base)
+            .field("variants", &self.variants.len())
+            .field("prefix", &self.prefix)
+            .field("manager", &self.manager)
+            .finish()
+    }
+}
+
+impl<V: 'static> Recipe<V> {
+    /// Creates a builder for a recipe with the styles that are applied to all combinations.
+    pub fn builder<Css>(base: Css) -> RecipeBuilder<V>
+    where
+        Css: Into<StyleSource<'static>>,
+    {
+        RecipeBuilder::new(base)
+    }
+
+    /// Returns the style for `variants`.
+    ///
+    /// The style is created when a combination is used for the first time.
+    pub fn style(&self, variants: &V) -> Result<Style> {
+        let matched = self
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(_, (predicate, _))| predicate(variants))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if let Some(m) = self.styles.borrow().get(&matched) {
+            return Ok(m.clone());
+        }
+
+        let mut sheet = self.base.clone();
+        for i in matched.iter() {
+            sheet.extend(&self.variants[*i].1);
+        }
+
+        let style = Style::create_with_manager(self.prefix.clone(), sheet, self.manager.clone())?;
+        self.styles.borrow_mut().insert(matched, style.clone());
+
+        Ok(style)
+    }
+
+    /// Returns the style for the default variants.
+    pub fn default_style(&self) -> Result<Style>
+    where
+        V: Default,
+    {
+        self.style(&V::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tone {
+        Neutral,
+        Danger,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Variants {
+        tone: Tone,
+        outlined: bool,
+    }
+
+    impl Default for Variants {
+        fn default() -> Self {
+            Self {
+                tone: Tone::Neutral,
+                outlined: false,
+            }
+        }
+    }
+
+    fn recipe() -> Recipe<Variants> {
+        Recipe::builder("padding: 4px;")
+            .variant(|m: &Variants| &m.tone, Tone::Danger, "color: red;")
+            .variant(|m: &Variants| &m.outlined, true, "border: 1px solid;")
+            .compound(
+                |m: &Variants| m.tone == Tone::Danger && m.outlined,
+                "border-color: red;",
+            )
+            .prefix("test-recipe".into())
+            .precompute([Variants::default()])
+            .build()
+            .expect("Failed to create Recipe.")
+    }
+
+    #[test]
+    fn test_recipe() {
+        let recipe = recipe();
+        assert_eq!(recipe.styles.borrow().len(), 1);
+
+        let style = recipe
+            .style(&Variants {
+                tone: Tone::Danger,
+                outlined: true,
+            })
+            .expect("Failed to create Style.");
+
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                r#".{cls} {{
+    padding: 4px;
+}}
+.{cls} {{
+    color: red;
+}}
+.{cls} {{
+    border: 1px solid;
+}}
+.{cls} {{
+    border-color: red;
+}}
+"#,
+                cls = style.get_class_name()
+            )
+        );
+    }
+
+    #[test]
+    fn test_defaults() {
+        let recipe = recipe();
+
+        let default_style = recipe.default_style().expect("Failed to create Style.");
+        let danger_style = recipe
+            .style(&Variants {
+                tone: Tone::Danger,
+                ..Default::default()
+            })
+            .expect("Failed to create Style.");
+
+        assert!(default_style.get_class_name().starts_with("test-recipe-"));
+        assert_ne!(
+            default_style.get_class_name(),
+            danger_style.get_class_name()
+        );
+        assert_eq!(
+            recipe
+                .style(&Variants::default())
+                .expect("Failed to create Style.")
+                .get_class_name(),
+            default_style.get_class_name()
+        );
+    }
+}
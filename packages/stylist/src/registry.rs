@@ -14,6 +14,7 @@ pub(crate) struct StyleKey {
     pub is_global: bool,
     pub prefix: Cow<'static, str>,
     pub ast: Sheet,
+    pub scope_keyframes: bool,
 }
 
 /// The style registry is a registry that keeps an instance of all styles for current manager.
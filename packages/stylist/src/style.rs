@@ -1,5 +1,9 @@
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::rc::Rc;
 
@@ -7,8 +11,10 @@ use std::rc::Rc;
 use stylist_core::ResultDisplay;
 
 use crate::ast::ToStyleStr;
+use crate::atomic::atomize;
 use crate::manager::StyleManager;
-use crate::registry::StyleKey;
+use crate::priority::with_priority;
+use crate::registry::{StyleKey, StyleRegistry};
 use crate::{Result, StyleSource};
 
 use crate::utils::get_entropy;
@@ -32,9 +38,103 @@ impl fmt::Display for StyleId {
     }
 }
 
+/// A type that refers to a class, which can be interpolated into selectors.
+///
+/// When a [`Style`] or a [`StyleId`] is interpolated into a selector of [`css!`](crate::css),
+/// it is printed as a class selector (`.stylist-uSu9NZZu`) instead of its [`Display`](fmt::Display)
+/// representation. A [`Style`] interpolated by a macro is kept mounted as long as the style
+/// referencing it is. Styles referenced in strings, e.g. with [`Style::get_class_name`], or in
+/// [`sheet!`](crate::ast::sheet) are not tracked.
+///
+/// The class selector of an atomic style (see
+/// [`StyleManagerBuilder::atomic`](crate::manager::StyleManagerBuilder::atomic)) is the compound
+/// of its atomic classes, e.g. `.a.b`, which also matches other elements with the same
+/// declarations. Reference a style that is not atomic if this is not desired.
+///
+/// ```
+/// use stylist::{css, Style};
+///
+/// let card = Style::new("padding: 4px;")?;
+///
+/// // .stylist-uSu9NZZu:hover .stylist-TzH4hzOt { color: red; }
+/// let title = Style::new(css!(
+///     ${card}:hover & {
+///         color: red;
+///     }
+/// ))?;
+/// # Ok::<(), stylist::Error>(())
+/// ```
+pub trait ClassSelector {
+    /// Returns the class selector, including the leading `.`.
+    fn class_selector(&self) -> String;
+}
+
+impl ClassSelector for StyleId {
+    fn class_selector(&self) -> String {
+        // Atomic styles have a class name for each declaration.
+        self.0
+            .split_whitespace()
+            .map(|m| format!(".{}", m))
+            .collect()
+    }
+}
+
+impl ClassSelector for Style {
+    fn class_selector(&self) -> String {
+        // Macros collect the styles interpolated into selectors while creating a source.
+        DEPENDENCIES.with(|m| {
+            if let Some(m) = m.borrow_mut().last_mut() {
+                m.push(self.clone());
+            }
+        });
+
+        self.inner.id().class_selector()
+    }
+}
+
+impl<T: ClassSelector + ?Sized> ClassSelector for &T {
+    fn class_selector(&self) -> String {
+        (**self).class_selector()
+    }
+}
+
+thread_local! {
+    // A stack of the styles interpolated into selectors, one for each source being created.
+    static DEPENDENCIES: RefCell<Vec<Vec<Style>>> = RefCell::default();
+
+    // The number of users of styles that are released by their users, by class name.
+    static USERS: RefCell<HashMap<String, usize>> = RefCell::default();
+}
+
+/// Calls `f` and returns its result with the styles interpolated into selectors by `f`.
+pub(crate) fn collect_dependencies<F, T>(f: F) -> (T, Vec<Style>)
+where
+    F: FnOnce() -> T,
+{
+    // Pops the styles of `f`, even if it panics.
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            DEPENDENCIES.with(|m| m.borrow_mut().pop());
+        }
+    }
+
+    DEPENDENCIES.with(|m| m.borrow_mut().push(Vec::new()));
+    let _guard = Guard;
+
+    let value = f();
+    let dependencies = DEPENDENCIES
+        .with(|m| m.borrow_mut().last_mut().map(std::mem::take))
+        .unwrap_or_default();
+
+    (value, dependencies)
+}
+
 #[derive(Debug)]
 pub(crate) struct StyleContent {
-    pub is_global: bool,
+    // An atomic style is composed of the atomic styles in dependencies and is not mounted.
+    pub is_atomic: bool,
 
     pub id: StyleId,
 
@@ -43,6 +143,11 @@ pub(crate) struct StyleContent {
     pub style_str: String,
 
     pub manager: StyleManager,
+
+    // Styles referenced in selectors, which are kept mounted with this style. Only held for
+    // their lifetime, so this is never read outside of tests.
+    #[allow(dead_code)]
+    pub dependencies: Vec<Rc<StyleContent>>,
 }
 
 impl StyleContent {
@@ -70,7 +175,9 @@ impl StyleContent {
 impl Drop for StyleContent {
     /// Unmounts the style from the HTML head web-sys style
     fn drop(&mut self) {
-        let _result = self.unmount();
+        if !self.is_atomic {
+            let _result = self.unmount();
+        }
     }
 }
 
@@ -158,6 +265,8 @@ impl Style {
         #[cfg(all(debug_assertions, feature = "parser"))]
         use crate::ast::Sheet;
 
+        let scope_keyframes = css.scopes_keyframes();
+        let dependencies = css.dependencies();
         let css = css.try_to_sheet()?;
 
         // Creates the StyleKey, return from registry if already cached.
@@ -165,6 +274,7 @@ impl Style {
             is_global: false,
             prefix: class_prefix,
             ast: css,
+            scope_keyframes,
         };
 
         let reg = manager.get_registry();
@@ -174,9 +284,23 @@ impl Style {
             return Ok(Style { inner: m });
         }
 
+        // Checks the stylesheet against the css property database in debug mode.
+        #[cfg(all(debug_assertions, feature = "validation"))]
+        key.ast.validate()?;
+
+        if manager.is_atomic() {
+            return Self::create_atomic(key, dependencies, manager, &mut reg);
+        }
+
         let id = StyleId(format!("{}-{}", key.prefix, get_entropy()));
 
-        let style_str = key.ast.to_style_str(Some(&id));
+        // Keyframes of macros are scoped to the style, so styles defining keyframes with the same
+        // name do not override each other.
+        let mut sheet = manager.transform(&key.ast, false);
+        if key.scope_keyframes {
+            sheet = sheet.scope_keyframes(&id);
+        }
+        let style_str = with_priority(&sheet, manager.priority()).to_style_str(Some(&id));
 
         // We parse the style str again in debug mode to ensure that interpolated values are
         // not corrupting the stylesheet.
@@ -187,11 +311,12 @@ impl Style {
 
         let new_style = Self {
             inner: StyleContent {
-                is_global: false,
+                is_atomic: false,
                 id,
                 style_str,
                 manager,
                 key: Rc::new(key),
+                dependencies,
             }
             .into(),
         };
@@ -204,6 +329,83 @@ impl Style {
         Ok(new_style)
     }
 
+    /// Creates a style that is composed of an atomic style for each declaration of `key`.
+    fn create_atomic(
+        key: StyleKey,
+        mut dependencies: Vec<Rc<StyleContent>>,
+        manager: StyleManager,
+        reg: &mut StyleRegistry,
+    ) -> Result<Self> {
+        let mut sheet = manager.transform(&key.ast, false);
+
+        // Keyframes cannot be split, so they are scoped to a name derived from the stylesheet
+        // that is the same for all styles with the same stylesheet.
+        if key.scope_keyframes {
+            let mut hasher = DefaultHasher::new();
+            key.ast.hash(&mut hasher);
+            let keyframes_prefix = format!("{}-{:x}", key.prefix, hasher.finish());
+
+            sheet = sheet.scope_keyframes(&keyframes_prefix);
+        }
+
+        let mut atoms = Vec::new();
+        for ast in atomize(&sheet, manager.priority()) {
+            let atom_key = StyleKey {
+                is_global: false,
+                prefix: key.prefix.clone(),
+                ast,
+                // Keyframes of the atoms are already scoped.
+                scope_keyframes: false,
+            };
+
+            if let Some(m) = reg.get(&atom_key) {
+                atoms.push(m);
+                continue;
+            }
+
+            let id = StyleId(format!("{}-{}", atom_key.prefix, get_entropy()));
+            let atom = Rc::new(StyleContent {
+                is_atomic: false,
+                style_str: atom_key.ast.to_style_str(Some(&id)),
+                id,
+                manager: manager.clone(),
+                key: Rc::new(atom_key),
+                dependencies: Vec::new(),
+            });
+
+            manager.mount(&atom)?;
+            reg.register(atom.clone());
+            atoms.push(atom);
+        }
+
+        let id = StyleId(
+            atoms
+                .iter()
+                .map(|m| m.id().0.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        let style_str = atoms.iter().map(|m| m.get_style_str()).collect::<String>();
+
+        dependencies.extend(atoms);
+
+        let new_style = Self {
+            inner: StyleContent {
+                is_atomic: true,
+                id,
+                style_str,
+                manager,
+                key: Rc::new(key),
+                dependencies,
+            }
+            .into(),
+        };
+
+        reg.register(new_style.inner.clone());
+
+        Ok(new_style)
+    }
+
     /// Creates a new style from some parsable css with a default prefix.
     ///
     /// # Examples
@@ -307,6 +509,10 @@ impl Style {
         self.inner.key()
     }
 
+    pub(crate) fn content(&self) -> &Rc<StyleContent> {
+        &self.inner
+    }
+
     /// Unregister current style from style registry.
     ///
     /// After calling this method, the style will be unmounted from DOM after all its clones are freed.
@@ -320,6 +526,36 @@ impl Style {
         reg.unregister(self.key());
     }
 
+    /// Counts a user of this style, which is unregistered after all of its users are released.
+    pub(crate) fn acquire(&self) {
+        USERS.with(|m| {
+            *m.borrow_mut()
+                .entry(self.get_class_name().to_string())
+                .or_default() += 1
+        });
+    }
+
+    /// Releases a user of this style and unregisters the style if it was the last one.
+    pub(crate) fn release(&self) {
+        let is_last = USERS.with(|m| {
+            let mut users = m.borrow_mut();
+            match users.get_mut(self.get_class_name()) {
+                Some(count) if *count > 1 => {
+                    *count -= 1;
+                    false
+                }
+                _ => {
+                    users.remove(self.get_class_name());
+                    true
+                }
+            }
+        });
+
+        if is_last {
+            self.unregister();
+        }
+    }
+
     /// Returns the [`StyleId`] for current style.
     pub fn id(&self) -> &StyleId {
         self.inner.id()
@@ -397,4 +633,206 @@ mod tests {
             )
         )
     }
+
+    #[test]
+    fn test_keyframes() {
+        let css = r#"
+            animation: fade 1s;
+            @keyframes fade {
+                from {
+                    opacity: 0;
+                }
+            }
+        "#;
+
+        // Keyframes of runtime styles keep their names.
+        let style = Style::new(css).expect("Failed to create Style.");
+        assert!(style.get_style_str().contains("@keyframes fade {"));
+
+        // Keyframes of macros are scoped to the style.
+        let style = Style::new(StyleSource::from(css).with_scoped_keyframes())
+            .expect("Failed to create Style.");
+
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                r#".{style_name} {{
+    animation: {style_name}-fade 1s;
+}}
+@keyframes {style_name}-fade {{
+    from {{
+        opacity: 0;
+    }}
+}}
+"#,
+                style_name = style.get_class_name()
+            )
+        )
+    }
+
+    #[test]
+    fn test_class_selector() {
+        let card =
+            Style::create("test-class-selector", "padding: 4px;").expect("Failed to create Style.");
+        assert_eq!(card.class_selector(), format!(".{}", card.get_class_name()));
+
+        // Styles referenced in strings are not tracked.
+        let title = Style::new(format!(".{} & {{ color: red; }}", card.get_class_name()))
+            .expect("Failed to create Style.");
+        assert!(title.inner.dependencies.is_empty());
+
+        // Like macros, collects the styles interpolated into selectors.
+        let source = crate::macros::collect_dependencies(|| {
+            format!("{} & {{ color: blue; }}", card.class_selector())
+        });
+        let title = Style::new(source).expect("Failed to create Style.");
+
+        // The referenced style stays mounted after it is unregistered.
+        card.unregister();
+        assert_eq!(title.inner.dependencies.len(), 1);
+        assert_eq!(title.inner.dependencies[0].id(), card.id());
+    }
+
+    #[test]
+    fn test_plugin() {
+        let manager = StyleManager::builder()
+            .plugin(|sheet: crate::ast::Sheet| {
+                sheet.map(|attr| {
+                    if attr.key == "margin-left" {
+                        attr.key = "margin-right".into();
+                    }
+                })
+            })
+            .build()
+            .expect("Failed to create manager.");
+
+        let style =
+            Style::new_with_manager("margin-left: 8px;", manager).expect("Failed to create Style.");
+
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                r#".{style_name} {{
+    margin-right: 8px;
+}}
+"#,
+                style_name = style.get_class_name()
+            )
+        )
+    }
+
+    #[cfg(feature = "rtl")]
+    #[test]
+    fn test_rtl() {
+        use crate::manager::Direction;
+
+        let manager = StyleManager::builder()
+            .direction(Direction::Rtl)
+            .build()
+            .expect("Failed to create manager.");
+
+        let style = Style::new_with_manager("margin-left: 8px; text-align: left;", manager)
+            .expect("Failed to create Style.");
+
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                r#".{style_name} {{
+    margin-right: 8px;
+    text-align: right;
+}}
+"#,
+                style_name = style.get_class_name()
+            )
+        )
+    }
+
+    #[cfg(feature = "autoprefixer")]
+    #[test]
+    fn test_autoprefixer() {
+        let manager = StyleManager::builder()
+            .targets("safari >= 12".parse().expect("Failed to parse targets."))
+            .build()
+            .expect("Failed to create manager.");
+
+        let style = Style::new_with_manager("user-select: none;", manager)
+            .expect("Failed to create Style.");
+
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                r#".{style_name} {{
+    -webkit-user-select: none;
+    user-select: none;
+}}
+"#,
+                style_name = style.get_class_name()
+            )
+        )
+    }
+
+    #[test]
+    fn test_atomic() {
+        let manager = StyleManager::builder()
+            .prefix("test-atomic".into())
+            .atomic(true)
+            .build()
+            .expect("Failed to create manager.");
+
+        let button = Style::new_with_manager("color: red; &:hover { color: blue; }", &manager)
+            .expect("Failed to create Style.");
+        let link = Style::new_with_manager("color: red; padding: 4px;", &manager)
+            .expect("Failed to create Style.");
+
+        let button_classes = button.get_class_name().split(' ').collect::<Vec<_>>();
+        let link_classes = link.get_class_name().split(' ').collect::<Vec<_>>();
+
+        assert_eq!(button_classes.len(), 2);
+        assert_eq!(link_classes.len(), 2);
+        // The declaration `color: red;` is shared.
+        assert_eq!(button_classes[0], link_classes[0]);
+        assert_ne!(button_classes[1], link_classes[1]);
+
+        assert_eq!(
+            button.get_style_str(),
+            format!(
+                r#".{red} {{
+    color: red;
+}}
+.{blue}.{blue}.{blue}.{blue}.{blue}:hover {{
+    color: blue;
+}}
+"#,
+                red = button_classes[0],
+                blue = button_classes[1]
+            )
+        );
+        assert_eq!(
+            button.class_selector(),
+            format!(".{}.{}", button_classes[0], button_classes[1])
+        );
+    }
+
+    #[test]
+    fn test_priority() {
+        let manager = StyleManager::builder()
+            .prefix("test-priority".into())
+            .priority(1)
+            .build()
+            .expect("Failed to create manager.");
+
+        let style =
+            Style::new_with_manager("color: red;", &manager).expect("Failed to create Style.");
+
+        assert_eq!(
+            style.get_style_str(),
+            format!(
+                r#".{style_name}.{style_name} {{
+    color: red;
+}}
+"#,
+                style_name = style.get_class_name()
+            )
+        );
+    }
 }
@@ -2,18 +2,20 @@
 use std::borrow::Cow;
 #[cfg(not(feature = "parser"))]
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 use crate::ast::Sheet;
 use crate::manager::StyleManager;
-use crate::Result;
-#[cfg(feature = "yew_integration")]
-use crate::Style;
+use crate::style::StyleContent;
+use crate::{Result, Style};
 
 #[cfg(feature = "parser")]
 #[derive(Debug, Clone, PartialEq)]
 enum SheetSource<'a> {
     String(Cow<'a, str>),
     Sheet(Sheet),
+    // Sources spliced with `...${base}`, which are parsed when the sheet is created.
+    Spread(Vec<StyleSource<'a>>),
 }
 
 #[cfg(not(feature = "parser"))]
@@ -39,7 +41,7 @@ enum SheetSource {
 /// let rendered = html! {<div class={s.clone()} />};
 /// let global_rendered = html! {<Global css={s} />};
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct StyleSource<'a> {
     #[cfg(feature = "parser")]
     inner: SheetSource<'a>,
@@ -50,6 +52,19 @@ pub struct StyleSource<'a> {
     _marker: PhantomData<&'a ()>,
 
     manager: Option<StyleManager>,
+    // Set by macros, whose keyframes are scoped to the style.
+    scope_keyframes: bool,
+    // Styles interpolated into selectors by macros, which are kept mounted with the style.
+    dependencies: Vec<Style>,
+}
+
+// Dependencies are derived from the sheet, so they are not compared.
+impl PartialEq for StyleSource<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+            && self.manager == other.manager
+            && self.scope_keyframes == other.scope_keyframes
+    }
 }
 
 impl StyleSource<'_> {
@@ -58,22 +73,122 @@ impl StyleSource<'_> {
             SheetSource::Sheet(ref m) => Ok(m.clone()),
             #[cfg(feature = "parser")]
             SheetSource::String(ref m) => m.parse::<Sheet>(),
+            #[cfg(feature = "parser")]
+            SheetSource::Spread(ref m) => {
+                let mut sheet = Sheet::new();
+                for source in m.iter() {
+                    sheet.extend(&source.try_to_sheet()?);
+                }
+
+                Ok(sheet)
+            }
         }
     }
 
-    #[cfg(feature = "yew_integration")]
+    #[cfg(any(
+        feature = "yew_integration",
+        feature = "leptos_integration",
+        feature = "dioxus_integration",
+        feature = "sycamore_integration"
+    ))]
     pub(crate) fn to_style(&self) -> Style {
         use stylist_core::ResultDisplay;
         Style::new_with_manager(self.clone(), self.manager.clone().unwrap_or_default())
             .expect_display("Failed to create style")
     }
 
+    /// Creates a new source with the contents of `other` appended to this source.
+    ///
+    /// Declarations of `other` take precedence over declarations of this source with the same
+    /// specificity. See [`Sheet::extend`] for details.
+    ///
+    /// ```rust
+    /// use stylist::{css, Style};
+    ///
+    /// let base = css!("color: red; padding: 4px;");
+    /// let style = Style::new(base.concat(css!("color: blue;"))?)?;
+    /// # Ok::<(), stylist::Error>(())
+    /// ```
+    pub fn concat<'b, S>(&self, other: S) -> Result<StyleSource<'static>>
+    where
+        S: Into<StyleSource<'b>>,
+    {
+        let other = other.into();
+        let mut sheet = self.try_to_sheet()?;
+        sheet.extend(&other.try_to_sheet()?);
+
+        Ok(StyleSource {
+            manager: self.manager.clone(),
+            scope_keyframes: self.scope_keyframes,
+            dependencies: [self.dependencies.as_slice(), other.dependencies.as_slice()].concat(),
+            ..sheet.into()
+        })
+    }
+
     #[doc(hidden)]
     pub fn with_manager(mut self, manager: StyleManager) -> Self {
         self.manager = Some(manager);
 
         self
     }
+
+    // Used by macros to scope the keyframes of the source to the style.
+    #[doc(hidden)]
+    pub fn with_scoped_keyframes(mut self) -> Self {
+        self.scope_keyframes = true;
+
+        self
+    }
+
+    pub(crate) fn scopes_keyframes(&self) -> bool {
+        self.scope_keyframes
+    }
+
+    pub(crate) fn with_dependencies(mut self, dependencies: Vec<Style>) -> Self {
+        self.dependencies.extend(dependencies);
+
+        self
+    }
+
+    pub(crate) fn dependencies(&self) -> Vec<Rc<StyleContent>> {
+        self.dependencies
+            .iter()
+            .map(|m| m.content().clone())
+            .collect()
+    }
+}
+
+impl<'a> StyleSource<'a> {
+    // Used by `...${base}` to splice sources into a stylesheet.
+    #[doc(hidden)]
+    pub fn from_spread(sources: Vec<StyleSource<'a>>) -> Self {
+        let dependencies = sources
+            .iter()
+            .flat_map(|m| m.dependencies.iter().cloned())
+            .collect();
+
+        #[cfg(feature = "parser")]
+        {
+            StyleSource {
+                inner: SheetSource::Spread(sources),
+                manager: None,
+                scope_keyframes: false,
+                dependencies,
+            }
+        }
+
+        // Without the parser, all sources are sheets.
+        #[cfg(not(feature = "parser"))]
+        {
+            let mut sheet = Sheet::new();
+            for source in sources {
+                let SheetSource::Sheet(ref m) = source.inner;
+                sheet.extend(m);
+            }
+
+            StyleSource::from(sheet).with_dependencies(dependencies)
+        }
+    }
 }
 
 impl From<Sheet> for StyleSource<'_> {
@@ -83,6 +198,8 @@ impl From<Sheet> for StyleSource<'_> {
             #[cfg(not(feature = "parser"))]
             _marker: PhantomData,
             manager: None,
+            scope_keyframes: false,
+            dependencies: Vec::new(),
         }
     }
 }
@@ -97,6 +214,8 @@ mod feat_parser {
             StyleSource {
                 inner: SheetSource::String(other.into()),
                 manager: None,
+                scope_keyframes: false,
+                dependencies: Vec::new(),
             }
         }
     }
@@ -106,6 +225,8 @@ mod feat_parser {
             StyleSource {
                 inner: SheetSource::String(other.into()),
                 manager: None,
+                scope_keyframes: false,
+                dependencies: Vec::new(),
             }
         }
     }
@@ -115,6 +236,8 @@ mod feat_parser {
             StyleSource {
                 inner: SheetSource::String(other),
                 manager: None,
+                scope_keyframes: false,
+                dependencies: Vec::new(),
             }
         }
     }
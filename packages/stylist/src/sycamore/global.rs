@@ -0,0 +1,43 @@
+use sycamore::prelude::*;
+
+use super::use_style_manager;
+use crate::{GlobalStyle, StyleSource};
+use stylist_core::ResultDisplay;
+
+/// The properties for [`Global`] Component, please see its documentation for usage.
+#[derive(Props, Debug)]
+pub struct GlobalProps {
+    /// The global stylesheet.
+    #[prop(setter(into))]
+    pub css: StyleSource<'static>,
+}
+
+/// A Global Style that will be applied to `<html />` tag, inspired by [emotion](https://emotion.sh).
+///
+/// The `css` attribute accepts any type that implements [`Into<StyleSource>`] and panics if the
+/// string failed to be parsed into a stylesheet. The style is unregistered when the component is
+/// removed.
+///
+/// # Example:
+///
+/// ```
+/// use sycamore::prelude::*;
+/// use stylist::sycamore::Global;
+///
+/// #[component]
+/// fn App() -> View {
+///     view! {
+///         Global(css="color: red;")
+///         div { "Hello World!" }
+///     }
+/// }
+/// ```
+#[component]
+pub fn Global(props: GlobalProps) -> View {
+    let global_style = GlobalStyle::new_with_manager(props.css, use_style_manager())
+        .expect_display("Failed to create style.");
+
+    on_cleanup(move || global_style.unregister());
+
+    View::default()
+}
@@ -0,0 +1,56 @@
+//! This module contains sycamore specific features.
+
+use std::borrow::Cow;
+
+use sycamore::prelude::{try_use_context, MaybeDyn};
+
+/// A procedural macro to use a specialized, contextual [`css!`](crate::css) macro.
+///
+/// The [`css!`](crate::css) macro in the body of the function uses the style manager provided by
+/// [`ManagerProvider`], or the default style manager if there is none. Apply this attribute
+/// before the [`component`](::sycamore::prelude::component) attribute.
+///
+/// # Example:
+///
+/// ```rust
+/// use sycamore::prelude::*;
+/// use stylist::sycamore::styled_component_impl;
+///
+/// #[styled_component_impl]
+/// #[component]
+/// fn MyStyledComponent() -> View {
+///     view! { div(class=css!("color: red;")) { "Hello World!" } }
+/// }
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::sycamore_styled_component_impl as styled_component_impl;
+
+use crate::manager::StyleManager;
+use crate::{Style, StyleSource};
+
+mod global;
+mod provider;
+
+pub use global::{Global, GlobalProps};
+pub use provider::{ManagerProvider, ManagerProviderProps};
+
+/// Returns the style manager provided by [`ManagerProvider`], or the default style manager if
+/// there is none.
+pub fn use_style_manager() -> StyleManager {
+    try_use_context::<StyleManager>().unwrap_or_default()
+}
+
+/// Uses the class name of the [`Style`] as the value of an attribute, like `class`.
+impl From<Style> for MaybeDyn<Option<Cow<'static, str>>> {
+    fn from(style: Style) -> Self {
+        MaybeDyn::Static(Some(style.get_class_name().to_string().into()))
+    }
+}
+
+/// Creates a [`Style`] and uses its class name as the value of an attribute, like `class`.
+impl From<StyleSource<'_>> for MaybeDyn<Option<Cow<'static, str>>> {
+    fn from(style_src: StyleSource<'_>) -> Self {
+        style_src.to_style().into()
+    }
+}
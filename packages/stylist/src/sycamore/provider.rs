@@ -0,0 +1,38 @@
+use sycamore::prelude::*;
+
+use crate::manager::StyleManager;
+
+/// The properties for [`ManagerProvider`] Component, please see its documentation for usage.
+#[derive(Props, Debug)]
+pub struct ManagerProviderProps {
+    /// The style manager provided to child components.
+    pub manager: StyleManager,
+    pub children: Children,
+}
+
+/// A Context Provider to provide a custom [`StyleManager`] to child components.
+///
+/// # Example:
+///
+/// ```
+/// use sycamore::prelude::*;
+/// use stylist::sycamore::ManagerProvider;
+/// use stylist::manager::StyleManager;
+///
+/// #[component]
+/// fn App() -> View {
+///     let mgr = StyleManager::builder().prefix("my-styles".into()).build().unwrap();
+///
+///     view! {
+///         ManagerProvider(manager=mgr) {
+///             div { "Hello World!" }
+///         }
+///     }
+/// }
+/// ```
+#[component]
+pub fn ManagerProvider(props: ManagerProviderProps) -> View {
+    provide_context(props.manager);
+
+    props.children.call()
+}
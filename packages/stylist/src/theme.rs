@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::ast::{Block, RuleBlockContent, ScopeContent, Sheet, StyleAttribute};
+use crate::manager::StyleManager;
+use crate::{GlobalStyle, Result, Style};
+
+/// A design token of a [`Theme`], which is stored in a CSS custom property.
+///
+/// The [`Display`](fmt::Display) implementation of a token prints a reference to the custom
+/// property (`var(--name)`), so tokens can be used directly as values in [`css!`](crate::css).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ThemeToken {
+    name: &'static str,
+}
+
+impl ThemeToken {
+    /// Creates a token that is stored in the custom property `--{name}`.
+    pub const fn new(name: &'static str) -> Self {
+        Self { name }
+    }
+
+    /// Returns the name of this token, without the leading `--`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the name of the custom property of this token (`--name`).
+    pub fn property(&self) -> String {
+        format!("--{}", self.name)
+    }
+}
+
+impl fmt::Display for ThemeToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "var(--{})", self.name)
+    }
+}
+
+/// A structured theme whose tokens are exposed as CSS custom properties.
+///
+/// This trait is usually implemented with `#[derive(Theme)]`, which maps every field to a token
+/// with the kebab-cased field name and creates an upper cased associated constant of type
+/// [`ThemeToken`] for each field (e.g.: `MyTheme::FONT_COLOR` for `font_color`). Referencing a
+/// constant that does not exist is a compile error.
+///
+/// ```
+/// use stylist::{css, Theme};
+///
+/// #[derive(Debug, Clone, PartialEq, Theme)]
+/// struct MyTheme {
+///     font_color: String,
+///     background_color: String,
+/// }
+///
+/// let theme = MyTheme {
+///     font_color: "black".to_string(),
+///     background_color: "white".to_string(),
+/// };
+///
+/// // Mounts `--font-color` and `--background-color` onto the root element.
+/// let _global = theme.to_global_style().expect("Failed to mount theme.");
+///
+/// // `color: var(--font-color);`
+/// let style = css!("color: ${MyTheme::FONT_COLOR};");
+/// ```
+pub trait Theme: Clone + PartialEq + 'static {
+    /// Returns the tokens of this theme and their values.
+    fn tokens(&self) -> Vec<(ThemeToken, Cow<'static, str>)>;
+
+    /// Creates a stylesheet that declares all tokens as custom properties.
+    fn to_sheet(&self) -> Sheet {
+        let content = self
+            .tokens()
+            .into_iter()
+            .map(|(token, value)| {
+                RuleBlockContent::StyleAttr(StyleAttribute {
+                    key: token.property().into(),
+                    value: vec![value.into()].into(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Sheet::from(vec![ScopeContent::Block(Block {
+            condition: Cow::Borrowed(&[]),
+            content: content.into(),
+        })])
+    }
+
+    /// Mounts the tokens of this theme onto the root element.
+    fn to_global_style(&self) -> Result<GlobalStyle> {
+        GlobalStyle::new(self.to_sheet())
+    }
+
+    /// Mounts the tokens of this theme onto the root element with a custom [`StyleManager`].
+    fn to_global_style_with_manager(&self, manager: StyleManager) -> Result<GlobalStyle> {
+        GlobalStyle::new_with_manager(self.to_sheet(), manager)
+    }
+
+    /// Mounts the tokens of this theme onto a class, so they only apply to elements with the
+    /// class and their descendants.
+    fn to_style(&self) -> Result<Style> {
+        Style::new(self.to_sheet())
+    }
+
+    /// Mounts the tokens of this theme onto a class with a custom [`StyleManager`].
+    fn to_style_with_manager(&self, manager: StyleManager) -> Result<Style> {
+        Style::new_with_manager(self.to_sheet(), manager)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::ToStyleStr;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestTheme;
+
+    impl TestTheme {
+        const PRIMARY: ThemeToken = ThemeToken::new("primary");
+    }
+
+    impl Theme for TestTheme {
+        fn tokens(&self) -> Vec<(ThemeToken, Cow<'static, str>)> {
+            vec![(Self::PRIMARY, "blue".into())]
+        }
+    }
+
+    #[test]
+    fn test_token() {
+        assert_eq!(TestTheme::PRIMARY.property(), "--primary");
+        assert_eq!(TestTheme::PRIMARY.to_string(), "var(--primary)");
+    }
+
+    #[test]
+    fn test_sheet() {
+        assert_eq!(
+            TestTheme.to_sheet().to_style_str(None),
+            ":root {\n    --primary: blue;\n}\n"
+        );
+    }
+}
@@ -6,10 +6,14 @@ mod use_media_query;
 #[cfg(feature = "yew_use_style")]
 mod use_style;
 
+mod use_theme;
+
 #[cfg_attr(documenting, doc(cfg(feature = "yew_use_media_query")))]
 #[cfg(feature = "yew_use_media_query")]
-pub use use_media_query::use_media_query;
+pub use use_media_query::{use_media_query, use_media_query_with_default};
 
 #[cfg_attr(documenting, doc(cfg(feature = "yew_use_style")))]
 #[cfg(feature = "yew_use_style")]
-pub use use_style::use_style;
+pub use use_style::{use_style, use_style_with_deps};
+
+pub use use_theme::use_theme;
@@ -1,35 +1,101 @@
-use gloo_events::EventListener;
 use yew::prelude::*;
 
-use crate::arch::window;
+use crate::manager::StyleManager;
 
 /// A hook to provide media query.
 ///
 /// This hook will return the result of whether the provided query matches and updates when the result
 /// changes.
+///
+/// The query is evaluated when the component is first rendered, so client-side rendered
+/// components start with its result. Returns `false` if there is no window to evaluate the query
+/// in, like when rendering on the server. Hydrated components should use
+/// [`use_media_query_with_default`] to render the same as on the server.
 #[cfg_attr(documenting, doc(cfg(feature = "yew_use_media_query")))]
 #[cfg(feature = "yew_use_media_query")]
 pub fn use_media_query(query: &str) -> bool {
-    let match_media = || {
-        window()
-            .ok()
-            .and_then(|m| m.match_media(query).ok())
-            .flatten()
-            .expect("Failed to query media")
+    let mgr = use_context::<StyleManager>().unwrap_or_default();
+    let matches = {
+        let mgr = mgr.clone();
+        let query = query.to_string();
+
+        move || {
+            mgr.get_media_query_registry()
+                .borrow()
+                .matches(&query)
+                .unwrap_or(false)
+        }
     };
 
-    let state = use_state(|| match_media().matches());
-    let state_clone = state.clone();
+    use_subscribed_media_query(mgr, query, matches)
+}
+
+/// A hook to provide media query, which returns `default` if there is no window to evaluate the
+/// query in, like when rendering on the server.
+///
+/// The first render always returns `default`, so a hydrated component renders the same as on the
+/// server. The result of the query is applied after the component is mounted.
+///
+/// Components using the same query with the same contextual style manager share a single
+/// listener for changes of the query.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use stylist::yew::use_media_query_with_default;
+///
+/// #[function_component(Comp)]
+/// fn comp() -> Html {
+///     // Renders the desktop layout on the server and before the component is mounted.
+///     let is_desktop = use_media_query_with_default("(min-width: 1024px)", true);
+///     html! {<div>{if is_desktop { "Desktop" } else { "Mobile" }}</div>}
+/// }
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "yew_use_media_query")))]
+#[cfg(feature = "yew_use_media_query")]
+pub fn use_media_query_with_default(query: &str, default: bool) -> bool {
+    let mgr = use_context::<StyleManager>().unwrap_or_default();
+
+    // Starts with the default, so the first render matches the one on the server when hydrating.
+    use_subscribed_media_query(mgr, query, move || default)
+}
+
+/// Starts with the result of `init` and follows the result of the query after the component is
+/// mounted.
+#[cfg(feature = "yew_use_media_query")]
+fn use_subscribed_media_query<F>(mgr: StyleManager, query: &str, init: F) -> bool
+where
+    F: FnOnce() -> bool,
+{
+    let state = use_state(init);
+
+    {
+        let state = state.clone();
+
+        // Effects do not run on the server.
+        use_effect_with_deps(
+            move |(mgr, query)| {
+                let subscription = {
+                    let state = state.clone();
+                    mgr.get_media_query_registry()
+                        .borrow_mut()
+                        .subscribe(query, move |m| state.set(m))
+                };
 
-    // Cached until end of component cycle.
-    use_state(move || {
-        let match_media = match_media();
-        let match_media_clone = match_media.clone();
+                // The result may differ from the initial state, e.g. the default of a hydrated
+                // component.
+                if let Some(ref m) = subscription {
+                    if m.matches() != *state {
+                        state.set(m.matches());
+                    }
+                }
 
-        EventListener::new(&match_media, "change", move |_event| {
-            state_clone.set(match_media_clone.matches());
-        })
-    });
+                move || drop(subscription)
+            },
+            (mgr, query.to_string()),
+        );
+    }
 
     *state
 }
@@ -29,3 +29,147 @@ pub fn use_style<'a, Css: Into<StyleSource<'a>>>(css: Css) -> Style {
     // It does not make sense to unmount a scoped style.
     Style::new_with_manager(css, mgr).expect_display("failed to create style")
 }
+
+/// A hook to create [`Style`]s that are memoized on `deps`.
+///
+/// `f` is only called if `deps` or the contextual style manager changed since the last render,
+/// so unchanged dependencies skip creating the stylesheet and looking it up in the registry.
+///
+/// Styles created by this hook are counted per style. When the dependencies change or the
+/// component is destroyed, the previous style is unregistered once no other component using this
+/// hook uses it, so it is unmounted after all other references to it are dropped.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use stylist::css;
+/// use stylist::yew::use_style_with_deps;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     color: String,
+/// }
+///
+/// #[function_component(Comp)]
+/// fn comp(props: &Props) -> Html {
+///     let style = use_style_with_deps(
+///         |color| css!(color: ${color};),
+///         props.color.clone(),
+///     );
+///     html!{<div class={style}>{"Hello world!"}</div>}
+/// }
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "yew_use_style")))]
+#[cfg(feature = "yew_use_style")]
+pub fn use_style_with_deps<'a, F, Css, D>(f: F, deps: D) -> Style
+where
+    F: FnOnce(&D) -> Css,
+    Css: Into<StyleSource<'a>>,
+    D: PartialEq + 'static,
+{
+    let mgr = use_context::<StyleManager>().unwrap_or_default();
+    let memo = use_mut_ref(|| None::<(StyleManager, D, UsedStyle)>);
+
+    let mut memo = memo.borrow_mut();
+    memoize_style(&mut memo, mgr, deps, f)
+}
+
+/// A style used by [`use_style_with_deps`], which is released when it is dropped.
+#[cfg(feature = "yew_use_style")]
+#[derive(Debug)]
+struct UsedStyle(Style);
+
+#[cfg(feature = "yew_use_style")]
+impl UsedStyle {
+    fn new(style: Style) -> Self {
+        style.acquire();
+        Self(style)
+    }
+}
+
+#[cfg(feature = "yew_use_style")]
+impl Drop for UsedStyle {
+    fn drop(&mut self) {
+        self.0.release();
+    }
+}
+
+/// Returns the style in `memo` if `mgr` and `deps` are unchanged, otherwise creates the style
+/// with `f` and stores it in `memo`, which releases the previous style.
+#[cfg(feature = "yew_use_style")]
+fn memoize_style<'a, F, Css, D>(
+    memo: &mut Option<(StyleManager, D, UsedStyle)>,
+    mgr: StyleManager,
+    deps: D,
+    f: F,
+) -> Style
+where
+    F: FnOnce(&D) -> Css,
+    Css: Into<StyleSource<'a>>,
+    D: PartialEq,
+{
+    if let Some((ref last_mgr, ref last_deps, ref style)) = *memo {
+        if last_mgr == &mgr && last_deps == &deps {
+            return style.0.clone();
+        }
+    }
+
+    let style =
+        Style::new_with_manager(f(&deps), mgr.clone()).expect_display("failed to create style");
+    // The new style is acquired before the previous one is released, as they may be the same.
+    *memo = Some((mgr, deps, UsedStyle::new(style.clone())));
+
+    style
+}
+
+#[cfg(all(test, feature = "yew_use_style"))]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_memoize_style() {
+        let mgr = StyleManager::builder()
+            .prefix("test-use-style".into())
+            .build()
+            .expect("Failed to create manager.");
+        let calls = Cell::new(0);
+        let f = |color: &&str| {
+            calls.set(calls.get() + 1);
+            format!("color: {};", color)
+        };
+
+        let mut memo = None;
+        let red = memoize_style(&mut memo, mgr.clone(), "red", f);
+        let red_again = memoize_style(&mut memo, mgr.clone(), "red", f);
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(red.get_class_name(), red_again.get_class_name());
+
+        // Another user of the same style.
+        let mut other_memo = None;
+        memoize_style(&mut other_memo, mgr.clone(), "blue", f);
+
+        let blue = memoize_style(&mut memo, mgr.clone(), "blue", f);
+        assert_eq!(calls.get(), 3);
+        assert_ne!(red.get_class_name(), blue.get_class_name());
+
+        // The previous style is unregistered after its last user is gone.
+        let new_red =
+            Style::new_with_manager("color: red;", mgr.clone()).expect("Failed to create style.");
+        assert_ne!(new_red.get_class_name(), red.get_class_name());
+
+        // The style is still registered while it is used by others.
+        drop(memo);
+        let shared =
+            Style::new_with_manager("color: blue;", mgr.clone()).expect("Failed to create style.");
+        assert_eq!(shared.get_class_name(), blue.get_class_name());
+
+        drop(other_memo);
+        let new_blue =
+            Style::new_with_manager("color: blue;", mgr).expect("Failed to create style.");
+        assert_ne!(new_blue.get_class_name(), blue.get_class_name());
+    }
+}
@@ -0,0 +1,32 @@
+use yew::prelude::*;
+
+use crate::Theme;
+
+/// A hook to retrieve the [`Theme`] provided by the nearest
+/// [`ThemeProvider`](crate::yew::ThemeProvider).
+///
+/// # Panics
+///
+/// Panics if the component is not a descendant of a `ThemeProvider` of the same theme type.
+///
+/// # Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use stylist::Theme;
+/// use stylist::yew::use_theme;
+///
+/// #[derive(Debug, Clone, PartialEq, Theme)]
+/// struct MyTheme {
+///     font_color: String,
+/// }
+///
+/// #[function_component(Comp)]
+/// fn comp() -> Html {
+///     let theme = use_theme::<MyTheme>();
+///     html! {<div>{format!("The font color is {}.", theme.font_color)}</div>}
+/// }
+/// ```
+pub fn use_theme<T: Theme>() -> T {
+    use_context::<T>().expect("use_theme must be used inside of a ThemeProvider")
+}
@@ -32,6 +32,58 @@ use yew::html::IntoPropValue;
 #[cfg(feature = "macros")]
 pub use stylist_macros::styled_component;
 
+/// A procedural macro to create a function component that renders an element with a style.
+///
+/// The macro takes the name of the component, optionally followed by additional fields of its
+/// properties, the tag of the rendered element and the style of the element.
+/// The style supports both syntaxes of [`css!`](crate::css) and can refer to the properties of
+/// the component as `props`.
+///
+/// The properties of the component are generated as `{Name}Props` and also contain `children`,
+/// `class`, `id`, `style`, `title`, `onclick`, `onkeydown`, `onfocus` and `onblur`, which are
+/// forwarded to the rendered element. Classes passed to `class` are added after the class of the
+/// style.
+///
+/// Additional fields marked with `#[attr]` are forwarded to the element as an attribute or a
+/// listener with the name of the field, e.g. `#[attr] href: String` or
+/// `#[attr] oninput: Option<Callback<InputEvent>>`. Raw identifiers like `r#type` are forwarded as
+/// `type`. Unmarked fields are only available to the style.
+///
+/// Like [`styled_component`], the style is created with the contextual style manager.
+///
+/// # Example:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use stylist::yew::styled;
+///
+/// styled!(Title: h1 {
+///     font-size: 2rem;
+/// });
+///
+/// styled!(pub Button {
+///     color: String,
+///     #[attr]
+///     #[prop_or_default]
+///     disabled: bool,
+/// }: button {
+///     color: ${&props.color};
+/// });
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <>
+///             <Title class="title">{"Hello World!"}</Title>
+///             <Button color="red">{"Click me!"}</Button>
+///         </>
+///     }
+/// }
+/// ```
+#[cfg_attr(documenting, doc(cfg(feature = "macros")))]
+#[cfg(feature = "macros")]
+pub use stylist_macros::styled;
+
 /// A procedural macro to use a specialized, contextual [`css!`](crate::css) macro.
 ///
 /// [`styled_component`] is implemented in terms of this, prefer that if possible.
@@ -78,6 +130,28 @@ pub use stylist_macros::styled_component_impl;
 ///     html!{<div class={style}>{"Hello world!"}</div>}
 /// }
 /// ```
+///
+/// Styles that depend on properties can be memoized on a tuple of dependencies with
+/// `use_style!((deps) => css)`. The stylesheet is only created again if the dependencies change,
+/// and the style of the previous dependencies is released.
+/// See [`use_style_with_deps`].
+///
+/// ```
+/// use yew::prelude::*;
+/// use stylist::yew::use_style;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct Props {
+///     color: String,
+/// }
+///
+/// #[function_component(Comp)]
+/// fn comp(props: &Props) -> Html {
+///     let color = props.color.clone();
+///     let style = use_style!((color.clone(),) => color: ${color};);
+///     html!{<div class={style}>{"Hello world!"}</div>}
+/// }
+/// ```
 #[cfg_attr(documenting, doc(cfg(feature = "yew_use_style")))]
 #[cfg(feature = "yew_use_style")]
 pub use stylist_macros::use_style;
@@ -95,7 +169,7 @@ mod hooks;
 mod provider;
 
 pub use global::{Global, GlobalProps};
-pub use provider::{ManagerProvider, ManagerProviderProps};
+pub use provider::{ManagerProvider, ManagerProviderProps, ThemeProvider, ThemeProviderProps};
 
 pub use hooks::*;
 
@@ -1,6 +1,9 @@
 use yew::prelude::*;
 
+use stylist_core::ResultDisplay;
+
 use crate::manager::StyleManager;
+use crate::Theme;
 
 /// The properties for [`ManagerProvider`] Component, please see its documentation for usage.
 #[derive(Properties, Clone, Debug, PartialEq)]
@@ -40,3 +43,62 @@ pub fn manager_provider(props: &ManagerProviderProps) -> Html {
         </ContextProvider<StyleManager>>
     }
 }
+
+/// The properties for [`ThemeProvider`] Component, please see its documentation for usage.
+#[derive(Properties, Clone, Debug, PartialEq)]
+pub struct ThemeProviderProps<T: Theme> {
+    pub theme: T,
+    pub children: Children,
+}
+
+/// A Context Provider to provide a [`Theme`] to child components.
+///
+/// The tokens of the theme are declared as custom properties on a class of a wrapper element,
+/// so nested providers can override the theme for a part of the page. The wrapper element is a
+/// `<div>` with `display: contents`, which does not affect the layout. The theme can be retrieved
+/// in child components with [`use_theme`](super::use_theme).
+///
+/// # Example:
+///
+/// ```
+/// use yew::prelude::*;
+/// use stylist::Theme;
+/// use stylist::yew::ThemeProvider;
+///
+/// #[derive(Debug, Clone, PartialEq, Theme)]
+/// struct MyTheme {
+///     font_color: String,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let theme = MyTheme {
+///         font_color: "black".to_string(),
+///     };
+///
+///     let children = Html::default();
+///     html! {
+///         <ThemeProvider<MyTheme> theme={theme}>
+///             {children}
+///         </ThemeProvider<MyTheme>>
+///     }
+/// }
+/// ```
+#[function_component(ThemeProvider)]
+pub fn theme_provider<T: Theme>(props: &ThemeProviderProps<T>) -> Html {
+    let mgr = use_context::<StyleManager>().unwrap_or_default();
+
+    // Each theme is mounted onto its own class, which is shared by providers of equal themes.
+    let style = props
+        .theme
+        .to_style_with_manager(mgr)
+        .expect_display("failed to create theme style");
+
+    html! {
+        <ContextProvider<T> context={props.theme.clone()}>
+            <div class={style} style="display: contents;">
+                {props.children.clone()}
+            </div>
+        </ContextProvider<T>>
+    }
+}
@@ -0,0 +1,86 @@
+use stylist::{css, Style};
+
+#[test]
+fn test_inline_class_selector() {
+    let card = Style::new("padding: 4px;").unwrap();
+    let title = Style::new(css! {
+        ${card}:hover & {
+            color: red;
+        }
+        ${card.id()} > & {
+            color: blue;
+        }
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{card}:hover .{cls} {{
+    color: red;
+}}
+.{card} > .{cls} {{
+    color: blue;
+}}
+"#,
+        card = card.get_class_name(),
+        cls = title.get_class_name()
+    );
+    assert_eq!(title.get_style_str(), expected);
+}
+
+#[test]
+fn test_literal_class_selector() {
+    let card = Style::new("padding: 8px;").unwrap();
+    let title = Style::new(css!(
+        r#"
+            ${card}:hover & {
+                color: red;
+            }
+            ${tag} {
+                color: blue;
+            }
+        "#,
+        card = card,
+        tag = "span",
+    ))
+    .unwrap();
+    let expected = format!(
+        r#".{card}:hover .{cls} {{
+    color: red;
+}}
+.{cls} span {{
+    color: blue;
+}}
+"#,
+        card = card.get_class_name(),
+        cls = title.get_class_name()
+    );
+    assert_eq!(title.get_style_str(), expected);
+}
+
+#[test]
+fn test_literal_class_name_interpolation() {
+    let card = Style::new("padding: 8px;").unwrap();
+    let title = Style::new(css!(
+        r#"
+            .${id} & {
+                color: red;
+            }
+            #${id} & {
+                color: blue;
+            }
+        "#,
+        id = card.id(),
+    ))
+    .unwrap();
+    let expected = format!(
+        r#".{card} .{cls} {{
+    color: red;
+}}
+#{card} .{cls} {{
+    color: blue;
+}}
+"#,
+        card = card.get_class_name(),
+        cls = title.get_class_name()
+    );
+    assert_eq!(title.get_style_str(), expected);
+}
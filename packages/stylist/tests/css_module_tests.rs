@@ -0,0 +1,59 @@
+use stylist::css_module;
+
+css_module!(struct CardStyles, r#"
+    .title {
+        font-weight: bold;
+    }
+    @media print {
+        .body > .footer-link {
+            display: none;
+        }
+    }
+"#);
+
+#[test]
+fn test_css_module() {
+    let styles = CardStyles::new().unwrap();
+    let cls = styles.style().get_class_name().to_string();
+
+    assert_eq!(styles.title, format!("{}-title", cls));
+    assert_eq!(styles.body, format!("{}-body", cls));
+    assert_eq!(styles.footer_link, format!("{}-footer-link", cls));
+
+    let expected = format!(
+        r#".{cls}-title {{
+    font-weight: bold;
+}}
+@media print {{
+    .{cls}-body > .{cls}-footer-link {{
+        display: none;
+    }}
+}}
+"#,
+        cls = cls
+    );
+    assert_eq!(styles.style().get_style_str(), expected);
+
+    // The stylesheet is only registered once.
+    let other = CardStyles::new().unwrap();
+    assert_eq!(other.title, styles.title);
+}
+
+css_module!(struct KeywordStyles, r#"
+    .type {
+        font-weight: bold;
+    }
+    .box .style {
+        color: red;
+    }
+"#);
+
+#[test]
+fn test_css_module_reserved_names() {
+    let styles = KeywordStyles::new().unwrap();
+    let cls = styles.style().get_class_name().to_string();
+
+    assert_eq!(styles.r#type, format!("{}-type", cls));
+    assert_eq!(styles.r#box, format!("{}-box", cls));
+    assert_eq!(styles.style, format!("{}-style", cls));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "dioxus_integration")]
+
+use dioxus::dioxus_core::{AttributeValue, IntoAttributeValue};
+use dioxus::prelude::*;
+use stylist::dioxus::{styled_component_impl, Global, ManagerProvider};
+use stylist::manager::StyleManager;
+use stylist::Style;
+
+#[styled_component_impl]
+#[component]
+fn Title() -> Element {
+    rsx! { h1 { class: css!("font-size: 2rem;"), "Hello World!" } }
+}
+
+#[component]
+fn App() -> Element {
+    let manager = use_hook(|| {
+        StyleManager::builder()
+            .prefix("dioxus-test".into())
+            .build()
+            .expect("Failed to create manager.")
+    });
+
+    rsx! {
+        ManagerProvider { manager,
+            Global { css: "color: red;" }
+            Title {}
+        }
+    }
+}
+
+#[test]
+fn test_dioxus_ssr_styled_component() {
+    let mut dom = VirtualDom::new(App);
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+
+    // `Global` does not render any element.
+    assert!(html.starts_with(r#"<h1 class="dioxus-test-"#), "{}", html);
+    assert!(!html.contains("color: red;"), "{}", html);
+}
+
+#[test]
+fn test_dioxus_ssr_default_manager() {
+    let mut dom = VirtualDom::new(Title);
+    dom.rebuild_in_place();
+    let html = dioxus_ssr::render(&dom);
+
+    assert!(html.starts_with(r#"<h1 class="stylist-"#), "{}", html);
+}
+
+#[test]
+fn test_dioxus_class_conversion() {
+    let style = Style::new("color: red;").expect("Failed to create style.");
+
+    assert_eq!(
+        style.clone().into_value(),
+        AttributeValue::Text(style.get_class_name().to_string())
+    );
+}
@@ -0,0 +1,25 @@
+use stylist::{include_css, Style};
+
+#[test]
+fn test_include_css() {
+    let padding = "4px";
+    let color = "red";
+    let style = Style::new(include_css!(
+        "tests/styles/button.css",
+        padding = padding,
+        color = color
+    ))
+    .unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    border: none;
+    padding: 4px;
+}}
+.{cls}:hover {{
+    color: red;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
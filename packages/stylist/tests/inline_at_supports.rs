@@ -7,7 +7,7 @@ fn main() {
     }
     .unwrap();
     let expected_result = format!(
-        r#"@supports (display:grid) {{
+        r#"@supports (display: grid) {{
     .{cls} {{
         background-color: grey;
     }}
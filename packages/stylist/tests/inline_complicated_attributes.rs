@@ -24,9 +24,7 @@ fn main() {
         // contains selector, begins with, ends with, spaced hyphenated
         a[href*="login"],
         a[href^="https://"],
-        // FIXME: should work, but incorrectly reparsed after emitting
-        // parsing in macro works fine.
-        //a[href$=".pdf" ],
+        a[href$=".pdf" ],
         a[rel~="tag"],
         a[lang|="en"]
         {
@@ -44,19 +42,19 @@ fn main() {
         r#".{cls} {{
     border: medium dashed green;
 }}
-.{cls}:checked+label {{
+.{cls}:checked + label {{
     color: #9799a7;
 }}
-.{cls}:nth-child(-n+4)~nav {{
+.{cls}:nth-child(-n+4) ~ nav {{
     max-height: 500px;
 }}
 .{cls}::first-letter {{
-    box-shadow: 3px 3px red,-1rem 0 0.4rem olive;
+    box-shadow: 3px 3px red, -1rem 0 0.4rem olive;
 }}
 .{cls} article span {{
-    box-shadow: inset 0 1px 2px rgba(0.32,0,0,15%);
+    box-shadow: inset 0 1px 2px rgba(0.32, 0, 0, 15%);
 }}
-.{cls} a[href *="login"], .{cls} a[href^="https://"], .{cls} a[rel~="tag"], .{cls} a[lang|="en"] {{
+.{cls} a[href*="login"], .{cls} a[href^="https://"], .{cls} a[href$=".pdf"], .{cls} a[rel~="tag"], .{cls} a[lang|="en"] {{
     background-image: url("images/pdf.png");
 }}
 .{cls} #content::after {{
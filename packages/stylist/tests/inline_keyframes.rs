@@ -0,0 +1,70 @@
+use stylist::Style;
+
+#[test]
+fn test_keyframes() {
+    let style = Style::new(stylist::css! {
+        animation: fade 1s ease-in, spin 2s;
+
+        @keyframes fade {
+            from {
+                opacity: 0;
+            }
+            50% {
+                opacity: 0.5;
+            }
+            to {
+                opacity: 1;
+            }
+        }
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    animation: {cls}-fade 1s ease-in, spin 2s;
+}}
+@keyframes {cls}-fade {{
+    from {{
+        opacity: 0;
+    }}
+    50% {{
+        opacity: 0.5;
+    }}
+    to {{
+        opacity: 1;
+    }}
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_keyframes_not_shared() {
+    let a = Style::new(stylist::css! {
+        animation-name: fade;
+        @keyframes fade {
+            0%, 100% {
+                opacity: 0;
+            }
+        }
+    })
+    .unwrap();
+    let b = Style::new(stylist::css! {
+        animation-name: fade;
+        @keyframes fade {
+            0%, 100% {
+                opacity: 1;
+            }
+        }
+    })
+    .unwrap();
+
+    assert!(a.get_style_str().contains(&format!(
+        "@keyframes {}-fade {{\n    0%, 100% {{",
+        a.get_class_name()
+    )));
+    assert!(b
+        .get_style_str()
+        .contains(&format!("animation-name: {}-fade;", b.get_class_name())));
+}
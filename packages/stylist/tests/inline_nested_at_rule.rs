@@ -13,14 +13,14 @@ fn main() {
     }
     .unwrap();
     let expected_reusult = format!(
-        r#"@supports (display:grid) {{
+        r#"@supports (display: grid) {{
     @media print {{
         .{cls} {{
             background-color: grey;
         }}
     }}
 }}
-@supports (display:grid) {{
+@supports (display: grid) {{
     @media print {{
         .{cls} {{
             color: blue;
@@ -0,0 +1,103 @@
+use stylist::Style;
+
+#[test]
+fn test_combinators() {
+    let style = Style::new(stylist::css! {
+        & > .child {
+            color: red;
+        }
+        &.a .b ~ .c {
+            color: blue;
+        }
+        > .direct+.sibling {
+            color: green;
+        }
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{cls} > .child {{
+    color: red;
+}}
+.{cls}.a .b ~ .c {{
+    color: blue;
+}}
+.{cls} > .direct + .sibling {{
+    color: green;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_pseudo_selectors() {
+    let style = Style::new(stylist::css! {
+        &::before {
+            content: "";
+        }
+        &:not(.a .b, :hover) {
+            color: red;
+        }
+        li:nth-child(2n + 1)::marker {
+            color: blue;
+        }
+        &:has(> img) {
+            display: block;
+        }
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{cls}::before {{
+    content: "";
+}}
+.{cls}:not(.a .b, :hover) {{
+    color: red;
+}}
+.{cls} li:nth-child(2n + 1)::marker {{
+    color: blue;
+}}
+.{cls}:has(> img) {{
+    display: block;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_attribute_selectors() {
+    let style = Style::new(stylist::css! {
+        a[target] , a[lang |= "en"] , a[href $= ".pdf" i] {
+            color: red;
+        }
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{cls} a[target], .{cls} a[lang|="en"], .{cls} a[href$=".pdf" i] {{
+    color: red;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_value_spacing() {
+    let style = Style::new(stylist::css! {
+        width: calc(100% - var(--gap));
+        margin: 0 auto;
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    width: calc(100% - var(--gap));
+    margin: 0 auto;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
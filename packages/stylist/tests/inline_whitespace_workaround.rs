@@ -4,10 +4,7 @@ fn main() {
         &.class-a.class-b {
             color: red;
         }
-        // FIXME: this test case is currently documenting a quirk with the inline style.
-        // Once it can be fixed (proc_macro_span, https://github.com/rust-lang/rust/issues/54725)
-        // update this test case output and the documentation
-        //       v-- whitespace not detected
+        // whitespace between compound selectors is detected from the source
         &.class-a .class-b {
             color: black;
         }
@@ -23,7 +20,7 @@ fn main() {
         r#".{cls}.class-a.class-b {{
     color: red;
 }}
-.{cls}.class-a.class-b {{
+.{cls}.class-a .class-b {{
     color: black;
 }}
 .{cls}.class-a *.class-b {{
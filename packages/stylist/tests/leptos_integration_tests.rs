@@ -0,0 +1,51 @@
+#![cfg(feature = "leptos_integration")]
+
+use leptos::oco::Oco;
+use leptos::prelude::*;
+use stylist::leptos::{styled_component_impl, Global, ManagerProvider};
+use stylist::manager::StyleManager;
+use stylist::Style;
+
+#[styled_component_impl]
+#[component]
+fn Title() -> impl IntoView {
+    let class = Oco::from(css!("font-size: 2rem;"));
+    view! { <h1 class=class>"Hello World!"</h1> }
+}
+
+#[test]
+fn test_leptos_ssr_styled_component() {
+    let manager = StyleManager::builder()
+        .prefix("leptos-test".into())
+        .build()
+        .expect("Failed to create manager.");
+
+    let html = Owner::new().with(|| {
+        view! {
+            <ManagerProvider manager=manager>
+                <Global css="color: red;" />
+                <Title />
+            </ManagerProvider>
+        }
+        .to_html()
+    });
+
+    // `Global` does not render any element.
+    assert!(html.contains(r#"<h1 class="leptos-test-"#), "{}", html);
+    assert!(!html.contains("color: red;"), "{}", html);
+}
+
+#[test]
+fn test_leptos_ssr_default_manager() {
+    let html = Owner::new().with(|| view! { <Title /> }.to_html());
+
+    assert!(html.starts_with(r#"<h1 class="stylist-"#), "{}", html);
+}
+
+#[test]
+fn test_leptos_class_conversion() {
+    let style = Style::new("color: red;").expect("Failed to create style.");
+    let class = Oco::from(style.clone());
+
+    assert_eq!(class.as_str(), style.get_class_name());
+}
@@ -5,4 +5,5 @@ fn test_macro_integrations() {
     t.compile_fail("tests/inline_integrations/*-fail.rs");
     t.compile_fail("tests/literal_integrations/*-fail.rs");
     t.compile_fail("tests/sc_integrations/*-fail.rs");
+    t.pass("tests/sc_integrations/*-pass.rs");
 }
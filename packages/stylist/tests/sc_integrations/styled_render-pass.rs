@@ -0,0 +1,44 @@
+use stylist::yew::styled;
+use yew::prelude::*;
+
+styled!(pub Button {
+    primary: bool,
+    #[attr]
+    #[prop_or_default]
+    r#type: Option<String>,
+    #[attr]
+    #[prop_or_default]
+    disabled: bool,
+    #[attr]
+    #[prop_or_default]
+    oninput: Option<Callback<InputEvent>>,
+}: button {
+    color: ${if props.primary { "red" } else { "black" }};
+});
+
+#[function_component(App)]
+pub fn app() -> Html {
+    let onclick = Callback::from(|_: MouseEvent| ());
+    let onkeydown = Callback::from(|_: KeyboardEvent| ());
+    let onfocus = Callback::from(|_: FocusEvent| ());
+
+    html! {
+        <Button
+            primary=true
+            disabled=true
+            class="extra"
+            id="button"
+            style="margin: 0;"
+            title="Click me"
+            {onclick}
+            {onkeydown}
+            {onfocus}
+        >
+            {"Click me!"}
+        </Button>
+    }
+}
+
+fn main() {
+    let _rendered = html! { <App /> };
+}
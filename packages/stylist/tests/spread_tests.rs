@@ -0,0 +1,98 @@
+use stylist::{css, Style};
+
+#[test]
+fn test_inline_spread() {
+    let base = css! {
+        color: red;
+        &:hover {
+            color: blue;
+        }
+    };
+    let style = Style::new(css! {
+        padding: 4px;
+        ...${base}
+        color: green;
+    })
+    .unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    padding: 4px;
+}}
+.{cls} {{
+    color: red;
+}}
+.{cls}:hover {{
+    color: blue;
+}}
+.{cls} {{
+    color: green;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_literal_spread() {
+    let base = css!("color: red;");
+    let style = Style::new(css!(
+        r#"
+            ...${base};
+            .child {
+                color: green;
+            }
+        "#,
+        base = base,
+    ))
+    .unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    color: red;
+}}
+.{cls} .child {{
+    color: green;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_concat() {
+    let style = Style::new(css!("color: red;").concat("color: blue;").unwrap()).unwrap();
+    let expected = format!(
+        r#".{cls} {{
+    color: red;
+}}
+.{cls} {{
+    color: blue;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}
+
+#[test]
+fn test_spread_invalid_string() {
+    let base = "color: red; {".to_string();
+
+    let literal = Style::new(css!("...${base};", base = base.as_str()));
+    assert!(literal.is_err());
+
+    let inline = Style::new(css! {
+        ...${base}
+        color: green;
+    });
+    assert!(inline.is_err());
+}
+
+#[test]
+fn test_sheet_spread() {
+    let base = css!("color: red;");
+    let sheet = stylist::ast::sheet!("...${base}; color: green;", base = base);
+
+    assert_eq!(sheet.to_string(), "color: red;\ncolor: green;\n");
+}
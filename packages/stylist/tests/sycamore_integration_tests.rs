@@ -0,0 +1,50 @@
+#![cfg(feature = "sycamore_integration")]
+
+use std::borrow::Cow;
+
+use stylist::manager::StyleManager;
+use stylist::sycamore::{styled_component_impl, Global, ManagerProvider};
+use stylist::Style;
+use sycamore::prelude::*;
+
+#[styled_component_impl]
+#[component]
+fn Title() -> View {
+    view! { h1(class=css!("font-size: 2rem;")) { "Hello World!" } }
+}
+
+#[test]
+fn test_sycamore_ssr_styled_component() {
+    let html = sycamore::render_to_string(|| {
+        let manager = StyleManager::builder()
+            .prefix("sycamore-test".into())
+            .build()
+            .expect("Failed to create manager.");
+
+        view! {
+            ManagerProvider(manager=manager) {
+                Global(css="color: red;")
+                Title {}
+            }
+        }
+    });
+
+    // `Global` does not render any element.
+    assert!(html.contains(r#"<h1 class="sycamore-test-"#), "{}", html);
+    assert!(!html.contains("color: red;"), "{}", html);
+}
+
+#[test]
+fn test_sycamore_ssr_default_manager() {
+    let html = sycamore::render_to_string(|| view! { Title {} });
+
+    assert!(html.contains(r#"<h1 class="stylist-"#), "{}", html);
+}
+
+#[test]
+fn test_sycamore_class_conversion() {
+    let style = Style::new("color: red;").expect("Failed to create style.");
+    let class = MaybeDyn::<Option<Cow<'static, str>>>::from(style.clone());
+
+    assert_eq!(class.evaluate().as_deref(), Some(style.get_class_name()));
+}
@@ -0,0 +1,71 @@
+use stylist::*;
+
+#[derive(Debug, Clone, PartialEq, Theme)]
+struct TestTheme {
+    font_color: String,
+    spacing: usize,
+}
+
+#[test]
+fn test_theme_tokens() {
+    let theme = TestTheme {
+        font_color: "black".to_string(),
+        spacing: 8,
+    };
+
+    assert_eq!(TestTheme::FONT_COLOR.property(), "--font-color");
+    assert_eq!(
+        theme.tokens(),
+        vec![
+            (TestTheme::FONT_COLOR, "black".into()),
+            (TestTheme::SPACING, "8".into()),
+        ]
+    );
+}
+
+#[test]
+fn test_theme_tokens_in_macros() {
+    let literal = Style::new(css!(
+        r#"
+            color: ${TestTheme::FONT_COLOR};
+            padding: ${TestTheme::SPACING};
+        "#
+    ))
+    .expect("Failed to create style.");
+    let inline = Style::new(css! {
+        color: ${TestTheme::FONT_COLOR};
+        padding: ${TestTheme::SPACING};
+    })
+    .expect("Failed to create style.");
+
+    for style in [literal, inline] {
+        let expected = format!(
+            r#".{cls} {{
+    color: var(--font-color);
+    padding: var(--spacing);
+}}
+"#,
+            cls = style.get_class_name()
+        );
+        assert_eq!(style.get_style_str(), expected);
+    }
+}
+
+#[test]
+fn test_theme_style() {
+    let theme = TestTheme {
+        font_color: "black".to_string(),
+        spacing: 8,
+    };
+    let style = theme.to_style().expect("Failed to create style.");
+
+    let expected = format!(
+        r#".{cls} {{
+    --font-color: black;
+    --spacing: 8;
+}}
+"#,
+        cls = style.get_class_name()
+    );
+    assert_eq!(style.get_style_str(), expected);
+}